@@ -1,6 +1,6 @@
 use std::error;
 use std::fs;
-use std::io::Read;
+use std::mem;
 use std::sync;
 use std::time;
 
@@ -8,17 +8,79 @@ use crossterm::event;
 use ratatui::{
     backend::Backend, layout, style, style::Stylize, symbols, text, widgets, widgets::Widget, Frame,
 };
-use temporal_client::{self, ClientOptionsBuilder};
+use temporal_client::{self, ClientOptionsBuilder, WorkflowClientTrait};
 use tokio::task;
 use url::Url;
 
 use crate::{
-    event::Event, settings::Settings, theme::Theme, tui::Tui, widgets::keybinds::KeybindsWidget,
-    widgets::workflow::WorkflowWidget, widgets::workflow_table::WorkflowTableWidget,
-    widgets::Keybindable, widgets::ViewWidget,
+    audit, codec, event::Event, keymap::Action, settings::Settings, theme::Theme, tui::Tui,
+    widgets::keybinds::KeybindsWidget, widgets::workflow::WorkflowWidget,
+    widgets::workflow_table::QueryInput, widgets::workflow_table::WorkflowTableWidget,
+    widgets::Keybindable, widgets::Navigation, widgets::ViewWidget,
 };
 
-const FOOTER_INFO_TEXT: [&str; 1] = ["(q) quit | (↑/j) move up | (↓/k) move down | (r) reload"];
+/// Seconds between automatic reloads when enabled without `auto_refresh_secs` in the settings.
+const DEFAULT_AUTO_REFRESH_SECS: u64 = 10;
+
+/// How many views each tab keeps to go back to, the oldest ones are dropped first.
+const MAX_PREVIOUS_VIEWS: usize = 16;
+
+/// How requests failing with transient errors, like when the server is briefly unavailable, are
+/// sent again before the error is shown. Requests that can't succeed, like an invalid query, fail
+/// right away.
+const RETRY_CONFIG: temporal_client::RetryConfig = temporal_client::RetryConfig {
+    initial_interval: time::Duration::from_millis(500),
+    randomization_factor: 0.2,
+    multiplier: 2.0,
+    max_interval: time::Duration::from_secs(2),
+    max_elapsed_time: Some(time::Duration::from_secs(10)),
+    max_retries: 3,
+};
+
+/// Keys handled by the [`App`] in every view, before the view gets them. Views bind their own
+/// `Ctrl` keys too, like `Ctrl+d` and `Ctrl+u` to page, so these must not take them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabKey {
+    SwitchNamespace,
+    SplitOrSwitchPane,
+    CloseOtherPane,
+    RecentActions,
+    ZenMode,
+    AutoRefresh,
+    NewTab,
+    NextTab,
+    PreviousTab,
+    CloseTab,
+}
+
+impl TabKey {
+    fn from_key(key: &event::KeyEvent) -> Option<TabKey> {
+        if key.modifiers != event::KeyModifiers::CONTROL {
+            return None;
+        }
+        match key.code {
+            event::KeyCode::Char('g') => Some(TabKey::SwitchNamespace),
+            event::KeyCode::Char('w') => Some(TabKey::SplitOrSwitchPane),
+            event::KeyCode::Char('o') => Some(TabKey::CloseOtherPane),
+            event::KeyCode::Char('a') => Some(TabKey::RecentActions),
+            event::KeyCode::Char('f') => Some(TabKey::ZenMode),
+            event::KeyCode::Char('e') => Some(TabKey::AutoRefresh),
+            event::KeyCode::Char('t') => Some(TabKey::NewTab),
+            event::KeyCode::Char('n') => Some(TabKey::NextTab),
+            event::KeyCode::Char('p') => Some(TabKey::PreviousTab),
+            event::KeyCode::Char('k') => Some(TabKey::CloseTab),
+            _ => None,
+        }
+    }
+}
+
+/// A client and settings for a namespace being switched to, connected in the background.
+#[derive(Debug)]
+struct NamespaceConnection {
+    namespace: String,
+    temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+    settings: sync::Arc<Settings>,
+}
 
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, anyhow::Error>;
@@ -29,18 +91,59 @@ pub struct App {
     /// Is the application running?
     running: bool,
     temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+    /// Kept to connect again when switching namespaces.
+    client_options: temporal_client::ClientOptions,
     /// Temporal namespace we are connected to.
     namespace: String,
-    /// The current [`ViewWidget`] being displayed.
-    view: ViewWidget,
+    /// The name of a namespace to switch to, while it's being typed.
+    namespace_input: Option<QueryInput>,
+    /// The namespace being switched to and the task connecting to it, whose tabs replace the
+    /// current ones once connected.
+    switching_namespace: Option<(
+        String,
+        task::JoinHandle<Result<NamespaceConnection, String>>,
+    )>,
+    /// Every namespace in the cluster, to complete `namespace_input` with.
+    namespaces: sync::Arc<sync::RwLock<Vec<String>>>,
+    /// Each tab's [`ViewWidget`], only the active tab is displayed and has focus.
+    tabs: Vec<ViewWidget>,
+    /// Index into `tabs` of the active tab.
+    active_tab: usize,
+    /// The views each tab was navigated from, the most recent last, restored as they were left
+    /// when going back.
+    previous_views: Vec<Vec<ViewWidget>>,
+    /// A [`ViewWidget`] displayed beside the active tab in split mode, without focus.
+    other_view: Option<ViewWidget>,
+    /// Whether `other_view` is displayed on the left of the active tab.
+    other_view_on_left: bool,
+    settings: sync::Arc<Settings>,
     /// The [`App`]'s [`Theme`] defines its colors.
     theme: Theme,
+    /// A notice displayed over the view until the next key press.
+    toast: Option<String>,
+    /// Whether to display the recent actions from the audit log over the view.
+    show_audit: bool,
+    /// Whether to display every keybind of the view over it.
+    show_help: bool,
+    /// Whether to hide the border, title and footer to give all the space to the view.
+    zen_mode: bool,
+    /// Whether to reload the active workflow table every `auto_refresh_secs`.
+    auto_refresh: bool,
 }
 
 impl App {
     /// Constructs a new instance of [`App`].
     pub async fn new(settings: &Settings) -> Result<Self, anyhow::Error> {
-        let theme = settings.theme()?;
+        let (theme, toast) = match settings.theme() {
+            Ok(theme) => (theme, None),
+            Err(e) => {
+                log::warn!("Falling back to default theme: {}", e);
+                (
+                    Theme::default(),
+                    Some(format!("Theme not applied, using default: {}", e)),
+                )
+            }
+        };
         let mut temporal_url = Url::parse(&settings.host)?;
         temporal_url
             .set_port(Some(settings.port))
@@ -48,49 +151,176 @@ impl App {
 
         log::debug!("Connecting to: {}", temporal_url);
 
-        let mut client_cert_file = fs::File::open(&settings.client_cert)?;
-        let mut client_cert = Vec::new();
-        client_cert_file.read_to_end(&mut client_cert)?;
-
-        let mut private_key_file = fs::File::open(&settings.client_private_key)?;
-        let mut client_private_key = Vec::new();
-        private_key_file.read_to_end(&mut client_private_key)?;
-
-        let mut server_root_ca_cert_file = fs::File::open(&settings.server_root_ca_cert)?;
-        let mut server_root_ca_cert = Vec::new();
-        server_root_ca_cert_file.read_to_end(&mut server_root_ca_cert)?;
+        let http_connect_proxy = match settings.proxy() {
+            Some(proxy) => {
+                let proxy_url = Url::parse(&proxy)?;
+                let target_addr = format!(
+                    "{}:{}",
+                    proxy_url
+                        .host_str()
+                        .ok_or(anyhow::anyhow!("proxy URL has no host: {}", proxy))?,
+                    proxy_url.port_or_known_default().unwrap_or(3128)
+                );
+                let basic_auth = if proxy_url.username().is_empty() {
+                    None
+                } else {
+                    Some((
+                        proxy_url.username().to_string(),
+                        proxy_url.password().unwrap_or_default().to_string(),
+                    ))
+                };
+                log::debug!("Connecting through proxy: {}", target_addr);
 
-        let client_tls_config = temporal_client::ClientTlsConfig {
-            client_cert,
-            client_private_key,
+                Some(temporal_client::HttpConnectProxyOptions {
+                    target_addr,
+                    basic_auth,
+                })
+            }
+            None => None,
         };
-        let tls_config = temporal_client::TlsConfig {
-            server_root_ca_cert: Some(server_root_ca_cert),
-            client_tls_config: Some(client_tls_config),
-            domain: None,
+
+        let keep_alive = match settings.keep_alive_interval {
+            0 => None,
+            interval => Some(temporal_client::ClientKeepAliveConfig {
+                interval: time::Duration::from_secs(interval),
+                timeout: time::Duration::from_secs(settings.keep_alive_timeout),
+            }),
         };
 
-        let client_options = ClientOptionsBuilder::default()
+        let mut client_options: ClientOptionsBuilder = Default::default();
+        client_options
             .target_url(temporal_url)
             .client_name("temporaltui-rs")
             .client_version("1.0.0")
-            .tls_cfg(tls_config)
-            .build()?;
+            .identity(settings.identity.clone())
+            .api_key(settings.api_key.clone())
+            .http_connect_proxy(http_connect_proxy)
+            .keep_alive(keep_alive)
+            .retry_config(RETRY_CONFIG);
+        if let Some(tls_config) = App::tls_config(settings)? {
+            client_options.tls_cfg(tls_config);
+        }
+        let client_options = client_options.build()?;
 
         let namespace = settings.namespace.clone();
-        let temporal_client = sync::Arc::new(client_options.connect(&namespace, None).await?);
+        let connect_timeout = time::Duration::from_secs(settings.connect_timeout);
+        let temporal_client = sync::Arc::new(
+            tokio::time::timeout(connect_timeout, client_options.connect(&namespace, None))
+                .await
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "timed out connecting to Temporal after {}s",
+                        settings.connect_timeout
+                    )
+                })??,
+        );
 
-        let workflow_table = WorkflowTableWidget::new(&temporal_client, theme, 48);
+        let mut settings = settings.clone();
+        settings.retention = match settings.configured_retention() {
+            Some(retention) => Some(retention),
+            None => App::fetch_retention(&temporal_client).await,
+        };
+        let settings = sync::Arc::new(settings);
+        let mut workflow_table = WorkflowTableWidget::new(&temporal_client, &settings, theme);
+        if let Some(query) = settings.default_query.as_ref() {
+            workflow_table = workflow_table.with_query(query);
+        }
+        let auto_refresh = settings.auto_refresh_secs.is_some();
 
         Ok(App {
             running: true,
             temporal_client,
+            client_options,
             namespace,
-            view: ViewWidget::WorkflowTable(workflow_table),
+            namespace_input: None,
+            switching_namespace: None,
+            namespaces: sync::Arc::new(sync::RwLock::new(Vec::new())),
+            tabs: vec![ViewWidget::WorkflowTable(workflow_table)],
+            active_tab: 0,
+            previous_views: vec![Vec::new()],
+            other_view: None,
+            other_view_on_left: false,
+            settings,
             theme,
+            toast,
+            show_audit: false,
+            show_help: false,
+            zen_mode: false,
+            auto_refresh,
         })
     }
 
+    /// How to secure the connection: with a client certificate for mTLS, with only server TLS
+    /// when authenticating with an API key, or not at all when no certificates are configured.
+    fn tls_config(
+        settings: &Settings,
+    ) -> Result<Option<temporal_client::TlsConfig>, anyhow::Error> {
+        if !settings.tls {
+            return Ok(None);
+        }
+
+        let server_root_ca_cert = settings
+            .server_root_ca_cert
+            .as_ref()
+            .map(fs::read)
+            .transpose()?;
+
+        let client_tls_config = match (
+            settings.api_key.as_ref(),
+            settings.client_cert.as_ref(),
+            settings.client_private_key.as_ref(),
+        ) {
+            (Some(_), _, _) => None,
+            (None, Some(client_cert), Some(client_private_key)) => {
+                Some(temporal_client::ClientTlsConfig {
+                    client_cert: fs::read(client_cert)?,
+                    client_private_key: fs::read(client_private_key)?,
+                })
+            }
+            (None, None, None) if server_root_ca_cert.is_none() => {
+                log::debug!("Connecting in plaintext, as no certificates nor API key are set");
+                return Ok(None);
+            }
+            (None, client_cert, client_private_key) => {
+                let missing: Vec<&str> = [
+                    ("client_cert", client_cert.is_none()),
+                    ("client_private_key", client_private_key.is_none()),
+                ]
+                .into_iter()
+                .filter_map(|(name, is_missing)| is_missing.then_some(name))
+                .collect();
+                return Err(anyhow::anyhow!(
+                    "{} must also be set to connect with mTLS, or leave out server_root_ca_cert, \
+                     client_cert and client_private_key to connect in plaintext",
+                    missing.join(" and ")
+                ));
+            }
+        };
+
+        Ok(Some(temporal_client::TlsConfig {
+            server_root_ca_cert,
+            client_tls_config,
+            domain: None,
+        }))
+    }
+
+    /// Fetch how long the namespace retains closed workflows for.
+    async fn fetch_retention(
+        temporal_client: &temporal_client::RetryClient<temporal_client::Client>,
+    ) -> Option<time::Duration> {
+        let namespace = temporal_client::Namespace::Name(temporal_client.namespace().to_string());
+        match temporal_client.describe_namespace(namespace).await {
+            Ok(response) => response
+                .config
+                .and_then(|config| config.workflow_execution_retention_ttl)
+                .and_then(|ttl| time::Duration::try_from(ttl).ok()),
+            Err(e) => {
+                log::warn!("Failed to describe namespace to fetch retention: {}", e);
+                None
+            }
+        }
+    }
+
     pub async fn run<B: Backend>(mut self, mut terminal: Tui<B>) -> Result<(), anyhow::Error> {
         terminal.init()?;
 
@@ -99,10 +329,34 @@ impl App {
         let period = time::Duration::from_secs_f32(1.0 / 60.0);
         let mut interval = tokio::time::interval(period);
 
+        let auto_refresh_period = time::Duration::from_secs(
+            self.settings
+                .auto_refresh_secs
+                .unwrap_or(DEFAULT_AUTO_REFRESH_SECS)
+                .max(1),
+        );
+        // Views already load when they start, so skip the first immediate tick
+        let mut auto_refresh_interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + auto_refresh_period,
+            auto_refresh_period,
+        );
+        auto_refresh_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         while self.running {
             tokio::select! {
                 _ = interval.tick() => { terminal.draw(&mut self)?; },
+                _ = auto_refresh_interval.tick(), if self.auto_refresh => self.auto_refresh().await,
                 Ok(event) = terminal.events.next() => self.handle_event(&event).await,
+                connection = async { (&mut self.switching_namespace.as_mut().unwrap().1).await },
+                    if self.switching_namespace.is_some() =>
+                {
+                    self.switching_namespace = None;
+                    match connection {
+                        Ok(Ok(connection)) => self.use_namespace(connection).await,
+                        Ok(Err(message)) => self.toast = Some(message),
+                        Err(e) => log::error!("Failed to switch namespace: {}", e),
+                    }
+                }
             }
         }
 
@@ -111,20 +365,256 @@ impl App {
     }
 
     pub async fn run_view(&mut self) {
-        self.view.run().await;
+        let auto_refresh = self.auto_refresh;
+        self.view_mut().set_auto_refresh(auto_refresh);
+        self.view_mut().run().await;
+    }
+
+    /// Reload the active view if it's a workflow table, unless it's still loading or a query is
+    /// being typed.
+    async fn auto_refresh(&mut self) {
+        if let ViewWidget::WorkflowTable(workflow_table) = self.view() {
+            if !workflow_table.is_loading() && !workflow_table.is_querying() {
+                log::debug!("Auto refreshing workflow table");
+                workflow_table.reload().await;
+            }
+        }
+    }
+
+    /// Turn automatic reloads of the workflow table on or off.
+    fn toggle_auto_refresh(&mut self) {
+        self.auto_refresh = !self.auto_refresh;
+        log::info!("Auto refresh enabled: {}", self.auto_refresh);
+        for view in self
+            .tabs
+            .iter_mut()
+            .chain(self.other_view.as_mut())
+            .chain(self.previous_views.iter_mut().flatten())
+        {
+            view.set_auto_refresh(self.auto_refresh);
+        }
+    }
+
+    /// The [`ViewWidget`] of the active tab.
+    fn view(&self) -> &ViewWidget {
+        &self.tabs[self.active_tab]
+    }
+
+    fn view_mut(&mut self) -> &mut ViewWidget {
+        &mut self.tabs[self.active_tab]
+    }
+
+    /// Open a new tab with a copy of the active workflow table, whose query and filters can then
+    /// be changed without affecting the original.
+    pub async fn duplicate_tab(&mut self) {
+        if let ViewWidget::WorkflowTable(workflow_table) = self.view() {
+            let duplicate = ViewWidget::WorkflowTable(workflow_table.duplicate());
+            self.tabs.insert(self.active_tab + 1, duplicate);
+            self.previous_views.insert(self.active_tab + 1, Vec::new());
+            self.active_tab += 1;
+            self.run_view().await;
+        }
+    }
+
+    /// Move to the tab `offset` tabs away from the active one, wrapping around.
+    pub fn switch_tab(&mut self, offset: isize) {
+        let len = self.tabs.len() as isize;
+        self.active_tab = (self.active_tab as isize + offset).rem_euclid(len) as usize;
+    }
+
+    /// Close the active tab, unless it's the last one.
+    pub fn close_tab(&mut self) {
+        if self.tabs.len() > 1 {
+            self.tabs.remove(self.active_tab).shutdown();
+            for view in self.previous_views.remove(self.active_tab) {
+                view.shutdown();
+            }
+            self.active_tab = self.active_tab.min(self.tabs.len() - 1);
+        }
+    }
+
+    /// Start asking for the namespace to switch to, listing the namespaces in the background to
+    /// complete it with.
+    pub fn ask_namespace(&mut self) {
+        self.namespace_input =
+            Some(QueryInput::new("Enter a namespace...", self.theme).with_subject("namespace"));
+
+        let temporal_client = self.temporal_client.clone();
+        let namespaces = self.namespaces.clone();
+        task::spawn(async move {
+            match temporal_client.list_namespaces().await {
+                Ok(response) => {
+                    *namespaces.write().unwrap() = response
+                        .namespaces
+                        .into_iter()
+                        .filter_map(|namespace| namespace.namespace_info)
+                        .map(|info| info.name)
+                        .collect();
+                }
+                Err(e) => log::warn!("Failed to list namespaces: {}", e),
+            }
+        });
+    }
+
+    /// The namespaces starting with what's typed in `namespace_input`.
+    fn matching_namespaces(&self) -> Vec<String> {
+        let typed = self
+            .namespace_input
+            .as_ref()
+            .map(QueryInput::query)
+            .unwrap_or_default();
+        self.namespaces
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|namespace| namespace.starts_with(&typed))
+            .cloned()
+            .collect()
+    }
+
+    async fn handle_namespace_key(&mut self, key: event::KeyEvent) {
+        let Some(namespace_input) = self.namespace_input.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            event::KeyCode::Enter => {
+                let namespace = namespace_input.query();
+                if namespace.is_empty() {
+                    namespace_input.set_error("empty", 0);
+                } else {
+                    self.namespace_input = None;
+                    self.switch_namespace(&namespace);
+                }
+            }
+            event::KeyCode::Esc => self.namespace_input = None,
+            event::KeyCode::Tab => {
+                if let Some(namespace) = self.matching_namespaces().first() {
+                    if let Some(namespace_input) = self.namespace_input.as_mut() {
+                        namespace_input.set_query(namespace);
+                    }
+                }
+            }
+            _ => {
+                namespace_input.handle_key(key).await;
+            }
+        }
+    }
+
+    /// Connect to `namespace` in the background, to start over from a fresh workflow table once
+    /// connected. Until then, and on failure, the current namespace stays in use.
+    pub fn switch_namespace(&mut self, namespace: &str) {
+        log::info!(namespace = namespace; "Switching namespace");
+
+        // Only the last namespace asked for is switched to
+        if let Some((_, connecting)) = self.switching_namespace.take() {
+            connecting.abort();
+        }
+
+        let client_options = self.client_options.clone();
+        let settings = self.settings.clone();
+        let connecting = tokio::spawn(App::connect_namespace(
+            client_options,
+            settings,
+            namespace.to_string(),
+        ));
+        self.switching_namespace = Some((namespace.to_string(), connecting));
+    }
+
+    /// Connect to `namespace`, returning why not as a message to display on failure.
+    async fn connect_namespace(
+        client_options: temporal_client::ClientOptions,
+        settings: sync::Arc<Settings>,
+        namespace: String,
+    ) -> Result<NamespaceConnection, String> {
+        let connect_timeout = time::Duration::from_secs(settings.connect_timeout);
+        let temporal_client = match tokio::time::timeout(
+            connect_timeout,
+            client_options.connect(namespace.as_str(), None),
+        )
+        .await
+        {
+            Ok(Ok(temporal_client)) => sync::Arc::new(temporal_client),
+            Ok(Err(e)) => {
+                log::error!(namespace = namespace.as_str(); "Failed to connect: {}", e);
+                return Err(format!("Failed to connect to {}: {}", namespace, e));
+            }
+            Err(_) => {
+                log::error!(namespace = namespace.as_str(); "Timed out connecting");
+                return Err(format!(
+                    "Timed out connecting to {} after {}s",
+                    namespace, settings.connect_timeout
+                ));
+            }
+        };
+
+        let mut settings = (*settings).clone();
+        settings.namespace = namespace.clone();
+        settings.retention = App::fetch_retention(&temporal_client)
+            .await
+            .or(settings.configured_retention());
+
+        Ok(NamespaceConnection {
+            namespace,
+            temporal_client,
+            settings: sync::Arc::new(settings),
+        })
+    }
+
+    /// Replace every view with a fresh workflow table of the namespace connected to.
+    async fn use_namespace(&mut self, connection: NamespaceConnection) {
+        let NamespaceConnection {
+            namespace,
+            temporal_client,
+            settings,
+        } = connection;
+
+        self.shutdown_views();
+        self.tabs = vec![ViewWidget::WorkflowTable(WorkflowTableWidget::new(
+            &temporal_client,
+            &settings,
+            self.theme,
+        ))];
+        self.active_tab = 0;
+        self.previous_views = vec![Vec::new()];
+        self.other_view = None;
+        self.temporal_client = temporal_client;
+        codec::set_namespace(&namespace);
+        self.namespace = namespace;
+        self.settings = settings;
+
+        self.run_view().await;
     }
 
     /// Handles the tick event of the terminal.
     pub fn tick(&self) {}
 
+    /// Shut down every view, in tabs, in the other pane and to go back to, so that only the views
+    /// themselves reference the client, and its connection is closed once they are replaced. Must
+    /// be done before connecting again, to not leave stale tasks and connections behind.
+    pub fn shutdown_views(&self) {
+        for view in self
+            .tabs
+            .iter()
+            .chain(self.other_view.as_ref())
+            .chain(self.previous_views.iter().flatten())
+        {
+            view.shutdown();
+        }
+    }
+
     /// Set running to false to quit the application.
     pub fn quit(&mut self) {
+        if let Some((_, connecting)) = self.switching_namespace.take() {
+            connecting.abort();
+        }
+        self.shutdown_views();
         self.running = false;
     }
 
     /// Render the current view on display with a header and footer.
     pub fn render_view(&mut self, frame: &mut Frame) {
-        let app_block = widgets::Block::bordered()
+        let mut app_block = widgets::Block::bordered()
             .title(
                 text::Line::from(self.title())
                     .centered()
@@ -134,14 +624,97 @@ impl App {
             .border_style(self.theme.border)
             .bg(self.theme.background);
 
-        let app_area = app_block.inner(frame.area());
-        frame.render_widget(&app_block, frame.area());
+        if self.settings.read_only {
+            app_block = app_block.title(
+                text::Line::from(" READ-ONLY ")
+                    .left_aligned()
+                    .bold()
+                    .fg(self.theme.warning_foreground)
+                    .bg(self.theme.warning_background),
+            );
+        }
 
-        let vertical =
-            &layout::Layout::vertical([layout::Constraint::Fill(1), layout::Constraint::Length(2)]);
-        let [body_area, footer_area] = vertical.areas(app_area);
+        let busy_message = match self.switching_namespace.as_ref() {
+            Some((namespace, _)) => Some(format!("Switching namespace to {}…", namespace)),
+            None => self.view().busy_message(),
+        };
+        if let Some(busy_message) = busy_message {
+            app_block = app_block.title(
+                text::Line::from(busy_message)
+                    .right_aligned()
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.running_background),
+            );
+        }
 
-        frame.render_widget(&self.view, body_area);
+        let app_area = if self.zen_mode {
+            frame.render_widget(
+                widgets::Block::new().bg(self.theme.background),
+                frame.area(),
+            );
+            frame.area()
+        } else {
+            frame.render_widget(&app_block, frame.area());
+            app_block.inner(frame.area())
+        };
+
+        let vertical = &layout::Layout::vertical([
+            layout::Constraint::Fill(1),
+            layout::Constraint::Length(if self.zen_mode { 0 } else { 2 }),
+        ]);
+        let [mut body_area, footer_area] = vertical.areas(app_area);
+
+        if self.tabs.len() > 1 {
+            let [tabs_area, rest_area] = layout::Layout::vertical([
+                layout::Constraint::Length(1),
+                layout::Constraint::Fill(1),
+            ])
+            .areas(body_area);
+            body_area = rest_area;
+
+            let titles = self.tabs.iter().enumerate().map(|(i, tab)| {
+                let title = tab.tab_title();
+                let title = match title.char_indices().nth(30) {
+                    Some((end, _)) => format!("{}…", &title[..end]),
+                    None => title,
+                };
+                format!("{}: {}", i + 1, title)
+            });
+            let tabs = widgets::Tabs::new(titles)
+                .select(self.active_tab)
+                .style(style::Style::new().fg(self.theme.foreground))
+                .highlight_style(style::Style::new().bg(self.theme.selection_background));
+            frame.render_widget(tabs, tabs_area);
+        }
+
+        match self.other_view.as_ref() {
+            Some(other_view) => {
+                let horizontal = &layout::Layout::horizontal([
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Fill(1),
+                ]);
+                let [left_area, right_area] = horizontal.areas(body_area);
+                let (view_area, other_view_area) = if self.other_view_on_left {
+                    (right_area, left_area)
+                } else {
+                    (left_area, right_area)
+                };
+
+                let focused_block = widgets::Block::new()
+                    .borders(widgets::Borders::TOP)
+                    .border_style(self.theme.selection_background)
+                    .title(text::Line::from("Focused").centered());
+                let other_block = widgets::Block::new()
+                    .borders(widgets::Borders::TOP)
+                    .border_style(self.theme.border);
+
+                frame.render_widget(self.view(), focused_block.inner(view_area));
+                frame.render_widget(&focused_block, view_area);
+                frame.render_widget(other_view, other_block.inner(other_view_area));
+                frame.render_widget(&other_block, other_view_area);
+            }
+            None => frame.render_widget(self.view(), body_area),
+        }
 
         let footer_horizontal = &layout::Layout::horizontal([
             layout::Constraint::Length(10),
@@ -151,43 +724,265 @@ impl App {
         ]);
         let [_, footer_center_area, _] = footer_horizontal.areas(footer_area);
 
-        let mut keybinds = KeybindsWidget::new(self.view.keybinds(), self.theme);
+        frame.render_widget(&self.keybinds(), footer_center_area);
+
+        if let Some(namespace_input) = self.namespace_input.as_ref() {
+            self.render_namespace_input(namespace_input, body_area, frame);
+        }
+
+        if self.show_help {
+            self.render_help(body_area, frame);
+        }
+
+        if self.show_audit {
+            self.render_audit(body_area, frame);
+        }
+
+        if let Some(toast) = self.toast.as_ref() {
+            self.render_toast(toast, body_area, frame);
+        }
+    }
+
+    /// The keybinds of the current view, along with the ones available everywhere.
+    fn keybinds(&self) -> KeybindsWidget {
+        let mut keybinds = KeybindsWidget::new(self.view().keybinds(), self.theme);
+        if !self.settings.read_only {
+            for keybind in self.view().write_keybinds() {
+                keybinds.push(*keybind);
+            }
+        }
+        if self.other_view.is_some() {
+            keybinds.push(("Switch pane", &["Ctrl+w"]));
+            keybinds.push(("Close other pane", &["Ctrl+o"]));
+        } else if let ViewWidget::Workflow(_) = self.view() {
+            keybinds.push(("Split", &["Ctrl+w"]));
+        }
+        if let ViewWidget::WorkflowTable(_) = self.view() {
+            keybinds.push(("New tab", &["Ctrl+t"]));
+            keybinds.push(("Auto refresh", &["Ctrl+e"]));
+        }
+        if self.tabs.len() > 1 {
+            keybinds.push(("Next/previous tab", &["Ctrl+n", "Ctrl+p"]));
+            keybinds.push(("Close tab", &["Ctrl+k"]));
+        }
+        keybinds.push(("Recent actions", &["Ctrl+a"]));
+        keybinds.push(("Zen mode", &["Ctrl+f"]));
+        keybinds.push(("Switch namespace", &["Ctrl+g"]));
+        keybinds.push(("Help", &["?"]));
         keybinds.push(("Quit", &["Ctrl+c"]));
-        // let mode_footer = widgets::Paragraph::new(text::Line::from(self.mode.as_str()))
-        //     .style(
-        //         style::Style::new()
-        //             .fg(self.theme.footer_foreground)
-        //             .bg(self.theme.footer_background),
-        //     )
-        //     .centered()
-        //     .block(widgets::Block::bordered().borders(widgets::Borders::NONE));
-        // frame.render_widget(&mode_footer, footer_left_area);
-
-        // let kebyinds_footer = widgets::Paragraph::new(text::Text::from_iter(FOOTER_INFO_TEXT))
-        //     .style(
-        //         style::Style::new()
-        //             .fg(self.theme.footer_foreground)
-        //             .bg(self.theme.footer_background),
-        //     )
-        //     .centered()
-        //     .block(widgets::Block::bordered().borders(widgets::Borders::NONE));
-        frame.render_widget(&keybinds, footer_center_area);
+        keybinds.with_keymap(&self.settings.keybinds)
+    }
+
+    /// Render the input of the namespace to switch to at the top of `area`, with the namespaces
+    /// it can be completed to below it.
+    fn render_namespace_input(
+        &self,
+        namespace_input: &QueryInput,
+        area: layout::Rect,
+        frame: &mut Frame,
+    ) {
+        let [input_area, completions_area, _] = layout::Layout::vertical([
+            layout::Constraint::Length(3),
+            layout::Constraint::Length(1),
+            layout::Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let completions = match self.matching_namespaces().as_slice() {
+            [] => "Enter to switch, Esc to cancel".to_string(),
+            namespaces => format!("Tab to complete: {}", namespaces.join(", ")),
+        };
+
+        frame.render_widget(widgets::Clear, input_area);
+        frame.render_widget(namespace_input, input_area);
+        frame.render_widget(
+            widgets::Paragraph::new(completions)
+                .fg(self.theme.foreground)
+                .bg(self.theme.background),
+            completions_area,
+        );
+    }
+
+    /// Render every keybind of the current view in a box centered over `area`, dimming the rest.
+    fn render_help(&self, area: layout::Rect, frame: &mut Frame) {
+        let frame_area = frame.area();
+        frame.buffer_mut().set_style(
+            frame_area,
+            style::Style::new().add_modifier(style::Modifier::DIM),
+        );
+
+        let lines = self.keybinds().to_lines();
+        let width = lines.iter().map(text::Line::width).max().unwrap_or(0) as u16 + 4;
+        let height = lines.len() as u16 + 2;
+        let help_area = layout::Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width: width.min(area.width),
+            height: height.min(area.height),
+        };
+
+        let help_block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(style::Style::new().fg(self.theme.border))
+            .title(text::Line::from("Keybinds").centered())
+            .title_bottom(text::Line::from("Esc or ? to close").right_aligned())
+            .padding(widgets::Padding::horizontal(1))
+            .bg(self.theme.background);
+
+        frame.render_widget(widgets::Clear, help_area);
+        frame.render_widget(
+            widgets::Paragraph::new(lines)
+                .fg(self.theme.foreground)
+                .block(help_block),
+            help_area,
+        );
+    }
+
+    /// Render the recent actions from the audit log centered over `area`.
+    fn render_audit(&self, area: layout::Rect, frame: &mut Frame) {
+        let [_, vertical_area, _] = layout::Layout::vertical([
+            layout::Constraint::Percentage(15),
+            layout::Constraint::Percentage(70),
+            layout::Constraint::Percentage(15),
+        ])
+        .areas(area);
+        let [_, audit_area, _] = layout::Layout::horizontal([
+            layout::Constraint::Percentage(10),
+            layout::Constraint::Percentage(80),
+            layout::Constraint::Percentage(10),
+        ])
+        .areas(vertical_area);
+
+        let entries = audit::recent();
+        let lines: Vec<text::Line> = if entries.is_empty() {
+            vec![text::Line::from("No actions recorded yet")]
+        } else {
+            entries
+                .iter()
+                .map(|entry| {
+                    let mut spans = vec![
+                        text::Span::from(format!("{} ", entry.time))
+                            .fg(self.theme.header_foreground),
+                        text::Span::from(format!("{}: {}", entry.workflow_id, entry.action)),
+                    ];
+                    if let Some(reason) = entry.reason.as_ref() {
+                        spans.push(text::Span::from(format!(" ({})", reason)));
+                    }
+                    text::Line::from(spans)
+                })
+                .collect()
+        };
+
+        let audit_block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(style::Style::new().fg(self.theme.border))
+            .title(text::Line::from("Recent actions").centered())
+            .title_bottom(text::Line::from("Ctrl+a to close").right_aligned())
+            .bg(self.theme.background);
+
+        frame.render_widget(widgets::Clear, audit_area);
+        frame.render_widget(
+            widgets::Paragraph::new(lines)
+                .fg(self.theme.foreground)
+                .wrap(widgets::Wrap { trim: false })
+                .block(audit_block),
+            audit_area,
+        );
+    }
+
+    /// Render a toast in the bottom right corner of `area`.
+    fn render_toast(&self, toast: &str, area: layout::Rect, frame: &mut Frame) {
+        let width = (toast.chars().count() as u16 + 4).min(area.width);
+        let toast_area = layout::Rect {
+            x: area.right().saturating_sub(width),
+            y: area.bottom().saturating_sub(3),
+            width,
+            height: 3.min(area.height),
+        };
+
+        let toast_block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(style::Style::new().fg(self.theme.failure_background))
+            .title(text::Line::from("Press any key to dismiss").right_aligned())
+            .bg(self.theme.background);
+
+        frame.render_widget(widgets::Clear, toast_area);
+        frame.render_widget(
+            widgets::Paragraph::new(toast)
+                .fg(self.theme.foreground)
+                .block(toast_block),
+            toast_area,
+        );
     }
 
     fn title(&self) -> String {
-        format!("Temporal TUI - {}", self.namespace)
+        let host = Url::parse(&self.settings.host)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or(self.settings.host.clone());
+
+        self.settings
+            .title
+            .replace("{namespace}", &self.namespace)
+            .replace("{host}", &host)
+            .replace("{port}", &self.settings.port.to_string())
+            .replace("{profile}", self.settings.profile.as_deref().unwrap_or(""))
+    }
+
+    /// Switch every view to `theme`.
+    pub async fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        for view in self
+            .tabs
+            .iter_mut()
+            .chain(self.other_view.as_mut())
+            .chain(self.previous_views.iter_mut().flatten())
+        {
+            view.set_theme(theme).await;
+        }
     }
 
     pub async fn handle_event(&mut self, event: &Event) {
         match event {
+            Event::ThemeReload(theme) => self.set_theme(*theme).await,
             Event::Key(key_event) => {
-                if let event::KeyEvent {
-                    code: event::KeyCode::Char('c'),
-                    modifiers: event::KeyModifiers::CONTROL,
-                    ..
-                } = key_event
-                {
+                if self.settings.keybinds.matches(Action::Quit, key_event) {
                     self.quit()
+                } else if self.namespace_input.is_some() {
+                    self.handle_namespace_key(*key_event).await
+                } else if let Some(tab_key) = TabKey::from_key(key_event) {
+                    self.handle_tab_key(tab_key).await
+                } else if self.show_help {
+                    // Other keys are ignored while the help is displayed
+                    if matches!(
+                        key_event.code,
+                        event::KeyCode::Esc | event::KeyCode::Char('?')
+                    ) {
+                        self.show_help = false;
+                    }
+                } else if key_event.code == event::KeyCode::Char('?') && !self.view().is_typing() {
+                    self.show_help = true;
+                } else if self.toast.take().is_some() {
+                    log::debug!("Dismissed toast with key {:?}", key_event.code);
+                } else if let Some(action) = self
+                    .view()
+                    .write_action(*key_event)
+                    .filter(|_| self.settings.read_only)
+                {
+                    log::info!("Refused {} in read-only mode", action);
+                    self.toast = Some(format!("{} is disabled in read-only mode", action));
+                } else if let Some(action) = self
+                    .view()
+                    .write_action(*key_event)
+                    .filter(|_| self.view().is_busy())
+                {
+                    // Navigation still works, but actions that change workflows wait for the
+                    // operation in flight to complete, so impatient keys don't repeat them
+                    log::info!("Refused {} while busy", action);
+                    self.toast = Some(format!(
+                        "{} is disabled until the view is done loading",
+                        action
+                    ));
                 } else {
                     self.handle_key(*key_event).await
                 }
@@ -196,15 +991,98 @@ impl App {
         }
     }
 
+    async fn handle_tab_key(&mut self, tab_key: TabKey) {
+        match tab_key {
+            TabKey::SwitchNamespace => self.ask_namespace(),
+            TabKey::SplitOrSwitchPane => self.split_or_switch_pane().await,
+            TabKey::CloseOtherPane => {
+                if let Some(other_view) = self.other_view.take() {
+                    other_view.shutdown();
+                }
+            }
+            TabKey::RecentActions => self.show_audit = !self.show_audit,
+            TabKey::ZenMode => self.zen_mode = !self.zen_mode,
+            TabKey::AutoRefresh => self.toggle_auto_refresh(),
+            TabKey::NewTab => self.duplicate_tab().await,
+            TabKey::NextTab => self.switch_tab(1),
+            TabKey::PreviousTab => self.switch_tab(-1),
+            TabKey::CloseTab => self.close_tab(),
+        }
+    }
+
+    /// Move focus to the other pane in split mode. Otherwise, when viewing a workflow, keep it on
+    /// the left and open the workflow table on the right to pick another one to compare with:
+    /// the table the workflow was opened from, as it was left, or a new one.
+    pub async fn split_or_switch_pane(&mut self) {
+        if let Some(other_view) = self.other_view.as_mut() {
+            mem::swap(&mut self.tabs[self.active_tab], other_view);
+            self.other_view_on_left = !self.other_view_on_left;
+        } else if let ViewWidget::Workflow(_) = self.view() {
+            let previous_views = &mut self.previous_views[self.active_tab];
+            let workflow_table = match previous_views.last() {
+                Some(ViewWidget::WorkflowTable(_)) => previous_views.pop(),
+                _ => None,
+            };
+            let is_new_table = workflow_table.is_none();
+            let workflow_table = workflow_table.unwrap_or_else(|| {
+                ViewWidget::WorkflowTable(WorkflowTableWidget::new(
+                    &self.temporal_client,
+                    &self.settings,
+                    self.theme,
+                ))
+            });
+
+            let workflow = mem::replace(self.view_mut(), workflow_table);
+            self.other_view = Some(workflow);
+            self.other_view_on_left = true;
+            if is_new_table {
+                self.run_view().await;
+            }
+        }
+    }
+
     pub async fn handle_key(&mut self, key: event::KeyEvent) {
-        let should_change_view = match &mut self.view {
-            ViewWidget::WorkflowTable(workflow_table) => workflow_table.handle_key(key).await,
-            ViewWidget::Workflow(workflow) => workflow.handle_key(key).await,
-        };
+        match self.view_mut().handle_key(key).await {
+            Some(Navigation::Push(view)) => {
+                // Keep the previous view running, to go back to it as it was
+                let previous_view = mem::replace(self.view_mut(), view);
+                let previous_views = &mut self.previous_views[self.active_tab];
+                previous_views.push(previous_view);
+                if previous_views.len() > MAX_PREVIOUS_VIEWS {
+                    previous_views.remove(0).shutdown();
+                }
+                self.run_view().await;
+            }
+            Some(Navigation::Back(fallback)) => match self.previous_views[self.active_tab].pop() {
+                Some(previous_view) => mem::replace(self.view_mut(), previous_view).shutdown(),
+                None => {
+                    mem::replace(self.view_mut(), fallback).shutdown();
+                    self.run_view().await;
+                }
+            },
+            None => {}
+        }
+    }
+}
 
-        if let Some(view) = should_change_view {
-            self.view = view;
-            self.run_view().await;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctrl(c: char) -> event::KeyEvent {
+        event::KeyEvent::new(event::KeyCode::Char(c), event::KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn leaves_the_ctrl_keys_of_views_to_them() {
+        // Page down/up, query mode, cancel workflow, reload and the raw response dump
+        for c in ['d', 'u', 'q', 'x', 'r', 'l'] {
+            assert_eq!(TabKey::from_key(&ctrl(c)), None, "Ctrl+{} is taken", c);
         }
+        assert_eq!(TabKey::from_key(&ctrl('k')), Some(TabKey::CloseTab));
+        assert_eq!(
+            TabKey::from_key(&event::KeyEvent::from(event::KeyCode::Char('k'))),
+            None
+        );
     }
 }
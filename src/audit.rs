@@ -0,0 +1,119 @@
+//! A record of the actions operators perform through the TUI, kept in a dedicated file apart
+//! from the debug log, as JSON lines.
+use std::collections;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path;
+use std::sync;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// How many of the latest entries are kept in memory to display.
+const RECENT_ENTRIES: usize = 50;
+
+static AUDIT_LOG: sync::OnceLock<AuditLog> = sync::OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the action was performed, in RFC 3339.
+    pub time: String,
+    pub identity: String,
+    pub action: String,
+    pub workflow_id: String,
+    pub run_id: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug)]
+struct AuditLog {
+    path: path::PathBuf,
+    identity: String,
+    recent: sync::RwLock<collections::VecDeque<AuditEntry>>,
+}
+
+impl AuditLog {
+    /// Open the audit log at `path`, loading its latest entries.
+    fn open(path: &path::Path, identity: &str) -> Self {
+        let mut recent = collections::VecDeque::with_capacity(RECENT_ENTRIES);
+        if let Ok(file) = fs::File::open(path) {
+            for line in io::BufReader::new(file).lines().map_while(Result::ok) {
+                if let Ok(entry) = serde_json::from_str::<AuditEntry>(&line) {
+                    if recent.len() == RECENT_ENTRIES {
+                        recent.pop_front();
+                    }
+                    recent.push_back(entry);
+                }
+            }
+        }
+
+        Self {
+            path: path.to_path_buf(),
+            identity: identity.to_string(),
+            recent: sync::RwLock::new(recent),
+        }
+    }
+
+    fn append(&self, entry: &AuditEntry) -> io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)
+    }
+}
+
+/// Start recording actions to the audit log at `path`, attributed to `identity`.
+pub fn init(path: &path::Path, identity: &str) {
+    if AUDIT_LOG.set(AuditLog::open(path, identity)).is_err() {
+        log::warn!("Audit log already initialized");
+    }
+}
+
+/// Record that `action` was performed on a workflow, and why if a `reason` was given.
+pub fn record(action: &str, workflow_id: &str, run_id: Option<&str>, reason: Option<&str>) {
+    let Some(audit_log) = AUDIT_LOG.get() else {
+        log::warn!(
+            "Audit log not initialized, not recording action: {}",
+            action
+        );
+        return;
+    };
+
+    let entry = AuditEntry {
+        time: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        identity: audit_log.identity.clone(),
+        action: action.to_string(),
+        workflow_id: workflow_id.to_string(),
+        run_id: run_id.map(str::to_string),
+        reason: reason.map(str::to_string),
+    };
+
+    if let Err(e) = audit_log.append(&entry) {
+        log::error!(
+            "Failed to write to audit log {}: {}",
+            audit_log.path.display(),
+            e
+        );
+    }
+
+    let mut recent = audit_log.recent.write().unwrap();
+    if recent.len() == RECENT_ENTRIES {
+        recent.pop_front();
+    }
+    recent.push_back(entry);
+}
+
+/// The latest recorded actions, newest first.
+pub fn recent() -> Vec<AuditEntry> {
+    match AUDIT_LOG.get() {
+        Some(audit_log) => audit_log
+            .recent
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    }
+}
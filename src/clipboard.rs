@@ -0,0 +1,12 @@
+//! Copy text to the system clipboard through the terminal, with an OSC 52 escape sequence, so
+//! that it also works over SSH without a clipboard daemon.
+use std::io::{self, Write};
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+
+/// Copy `text` to the clipboard of the terminal we are running in.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", BASE64_STANDARD.encode(text))?;
+    stdout.flush()
+}
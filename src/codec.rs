@@ -0,0 +1,129 @@
+//! Decoding payloads with a remote codec server, like payloads encrypted by a custom data
+//! converter, with the same `/decode` protocol Temporal Web uses.
+use std::collections;
+use std::sync;
+use std::time;
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde_derive::{Deserialize, Serialize};
+use temporal_sdk_core_protos::temporal::api::common::v1 as temporal_common;
+
+/// How long to wait for the codec server to decode a batch of payloads.
+const DECODE_TIMEOUT: time::Duration = time::Duration::from_secs(10);
+
+static CODEC: sync::OnceLock<Codec> = sync::OnceLock::new();
+
+#[derive(Debug)]
+struct Codec {
+    client: reqwest::Client,
+    /// URL of the codec server's `/decode` endpoint.
+    decode_url: String,
+    /// Sent along so servers can pick the keys of the namespace.
+    namespace: sync::RwLock<String>,
+}
+
+/// A payload as codec servers take and return them, with base64 encoded bytes.
+#[derive(Debug, Serialize, Deserialize)]
+struct CodecPayload {
+    #[serde(default)]
+    metadata: collections::HashMap<String, String>,
+    #[serde(default)]
+    data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CodecPayloads {
+    payloads: Vec<CodecPayload>,
+}
+
+impl From<&temporal_common::Payload> for CodecPayload {
+    fn from(payload: &temporal_common::Payload) -> Self {
+        Self {
+            metadata: payload
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.clone(), BASE64_STANDARD.encode(value)))
+                .collect(),
+            data: BASE64_STANDARD.encode(&payload.data),
+        }
+    }
+}
+
+/// Start decoding payloads with the codec server at `endpoint`, for workflows in `namespace`.
+pub fn init(endpoint: &str, namespace: &str) {
+    let codec = Codec {
+        client: reqwest::Client::builder()
+            .timeout(DECODE_TIMEOUT)
+            .build()
+            .unwrap_or_default(),
+        decode_url: format!("{}/decode", endpoint.trim_end_matches('/')),
+        namespace: sync::RwLock::new(namespace.to_string()),
+    };
+
+    if CODEC.set(codec).is_err() {
+        log::warn!("Codec already initialized");
+    }
+}
+
+/// Decode payloads of workflows in `namespace` from now on, like after switching to it.
+pub fn set_namespace(namespace: &str) {
+    if let Some(codec) = CODEC.get() {
+        *codec.namespace.write().unwrap() = namespace.to_string();
+    }
+}
+
+/// Whether a codec server is configured to decode payloads with.
+pub fn is_enabled() -> bool {
+    CODEC.get().is_some()
+}
+
+/// Replace `payloads` with their decoded form, if a codec server is configured. On failure,
+/// payloads are left as they were.
+pub async fn decode(payloads: Vec<&mut temporal_common::Payload>) -> Result<(), anyhow::Error> {
+    let Some(codec) = CODEC.get().filter(|_| !payloads.is_empty()) else {
+        return Ok(());
+    };
+
+    let namespace = codec.namespace.read().unwrap().clone();
+    let request = CodecPayloads {
+        payloads: payloads.iter().map(|p| CodecPayload::from(&**p)).collect(),
+    };
+    let response: CodecPayloads = codec
+        .client
+        .post(&codec.decode_url)
+        .header("X-Namespace", namespace)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    if response.payloads.len() != payloads.len() {
+        return Err(anyhow::anyhow!(
+            "codec server returned {} payloads for {}",
+            response.payloads.len(),
+            payloads.len()
+        ));
+    }
+
+    // Decode everything before replacing anything, to not leave payloads half decoded
+    let decoded = response
+        .payloads
+        .into_iter()
+        .map(|payload| {
+            let metadata = payload
+                .metadata
+                .into_iter()
+                .map(|(key, value)| Ok((key, BASE64_STANDARD.decode(value)?)))
+                .collect::<Result<collections::HashMap<String, Vec<u8>>, base64::DecodeError>>()?;
+            Ok((metadata, BASE64_STANDARD.decode(payload.data)?))
+        })
+        .collect::<Result<Vec<_>, base64::DecodeError>>()?;
+
+    for (payload, (metadata, data)) in payloads.into_iter().zip(decoded) {
+        payload.metadata = metadata;
+        payload.data = data;
+    }
+    Ok(())
+}
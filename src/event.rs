@@ -5,6 +5,11 @@ use futures::{FutureExt, StreamExt};
 use tokio::sync::mpsc;
 
 use crate::app::AppResult;
+use crate::settings::Settings;
+use crate::theme::Theme;
+
+/// How often to check whether the configuration file changed.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Terminal events.
 #[derive(Clone, Copy, Debug)]
@@ -17,6 +22,8 @@ pub enum Event {
     Mouse(MouseEvent),
     /// Terminal resize.
     Resize(u16, u16),
+    /// The theme changed in the configuration file.
+    ThemeReload(Theme),
 }
 
 /// Terminal event handler.
@@ -81,6 +88,47 @@ impl EventHandler {
         }
     }
 
+    /// Watch the configuration file `settings` were read from, sending the theme again whenever
+    /// the file is modified. Invalid themes are logged and not sent, to keep the current one.
+    pub fn watch_theme(&self, settings: Settings) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let modified = || async {
+                tokio::fs::metadata(&settings.config_path)
+                    .await
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+            };
+            let mut last_modified = modified().await;
+            let mut interval = tokio::time::interval(CONFIG_POLL_INTERVAL);
+
+            loop {
+                tokio::select! {
+                  _ = sender.closed() => {
+                    break;
+                  }
+                  _ = interval.tick() => {}
+                };
+
+                let current_modified = modified().await;
+                if current_modified == last_modified {
+                    continue;
+                }
+                last_modified = current_modified;
+
+                match settings.reload_theme() {
+                    Ok(theme) => {
+                        log::info!("Reloading theme from {}", settings.config_path.display());
+                        if sender.send(Event::ThemeReload(theme)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => log::error!("Keeping the current theme, failed to reload it: {}", e),
+                }
+            }
+        });
+    }
+
     /// Receive the next event from the handler thread.
     ///
     /// This function will always block the current thread if
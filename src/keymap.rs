@@ -0,0 +1,325 @@
+//! Keys bound to the most common actions, which can be changed in the `[keybinds]` table of the
+//! settings, like `reload = "F5"` or `next_row = ["j", "Down"]`.
+use std::collections;
+use std::fmt;
+use std::str;
+
+use crossterm::event::{self, KeyCode, KeyModifiers};
+use serde::de::{self, Deserialize, Deserializer};
+use serde_derive::Deserialize;
+
+/// An action that can be bound to different keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Reload,
+    NextRow,
+    PreviousRow,
+    /// Open the selected workflow from the table.
+    ViewWorkflow,
+    /// Go back to the previous view.
+    Back,
+}
+
+impl Action {
+    const ALL: [Action; 6] = [
+        Action::Quit,
+        Action::Reload,
+        Action::NextRow,
+        Action::PreviousRow,
+        Action::ViewWorkflow,
+        Action::Back,
+    ];
+
+    /// The keys bound to this action unless configured otherwise.
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["Ctrl+c"],
+            Action::Reload => &["Ctrl+r"],
+            Action::NextRow => &["j", "↓"],
+            Action::PreviousRow => &["k", "↑"],
+            Action::ViewWorkflow => &["Enter"],
+            Action::Back => &["Esc"],
+        }
+    }
+
+    /// How this action is labelled in the keybinds of views.
+    fn label(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Reload => "Reload",
+            Action::NextRow => "Down",
+            Action::PreviousRow => "Up",
+            Action::ViewWorkflow => "View workflow",
+            Action::Back => "Previous view",
+        }
+    }
+}
+
+/// A key, possibly pressed with modifiers, like `Ctrl+r`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Key {
+    fn matches(&self, key: &event::KeyEvent) -> bool {
+        Key::normalized(self.code, self.modifiers) == Key::normalized(key.code, key.modifiers)
+    }
+
+    /// Shift is implied by the character it produces, so `Shift+r` is the same key as `R`,
+    /// which terminals report with or without Shift.
+    fn normalized(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+        match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => (
+                KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers - KeyModifiers::SHIFT,
+            ),
+            KeyCode::Char(c) => (KeyCode::Char(c), modifiers),
+            _ => (code, modifiers),
+        }
+    }
+}
+
+impl str::FromStr for Key {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s;
+        // A lone "+" is a key too, so only split where something follows it
+        while let Some((modifier, key)) = rest
+            .split_once('+')
+            .filter(|(m, k)| !m.is_empty() && !k.is_empty())
+        {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier {} in key {}", modifier, s)),
+            };
+            rest = key;
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "space" => KeyCode::Char(' '),
+            "up" | "↑" => KeyCode::Up,
+            "down" | "↓" => KeyCode::Down,
+            "left" | "←" => KeyCode::Left,
+            "right" | "→" => KeyCode::Right,
+            "pgup" | "pageup" => KeyCode::PageUp,
+            "pgdn" | "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) => KeyCode::F(n),
+                None => {
+                    let mut chars = rest.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ => return Err(format!("unknown key {}", s)),
+                    }
+                }
+            },
+        };
+
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// The keys bound to each [`Action`].
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: collections::HashMap<Action, Vec<Key>>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| {
+                    let keys = action
+                        .default_keys()
+                        .iter()
+                        .map(|key| key.parse().expect("default keys are valid"))
+                        .collect();
+                    (*action, keys)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl KeyMap {
+    /// Whether `key` is bound to `action`.
+    pub fn matches(&self, action: Action, key: &event::KeyEvent) -> bool {
+        self.bindings
+            .get(&action)
+            .is_some_and(|keys| keys.iter().any(|k| k.matches(key)))
+    }
+
+    /// The keys bound to the action labelled `label` in the keybinds of views, if they were
+    /// changed from `default_keys`.
+    pub fn rebound_keys(&self, label: &str, default_keys: &[&str]) -> Option<Vec<String>> {
+        let action = Action::ALL
+            .into_iter()
+            .find(|action| action.label() == label && action.default_keys() == default_keys)?;
+        let keys: Vec<String> = self
+            .bindings
+            .get(&action)?
+            .iter()
+            .map(Key::to_string)
+            .collect();
+
+        if keys == default_keys {
+            None
+        } else {
+            Some(keys)
+        }
+    }
+}
+
+/// One key or many keys bound to an action in the settings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl<'de> Deserialize<'de> for KeyMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut keymap = KeyMap::default();
+
+        for (action, keys) in collections::HashMap::<Action, Keys>::deserialize(deserializer)? {
+            let keys = match keys {
+                Keys::One(key) => vec![key],
+                Keys::Many(keys) => keys,
+            };
+            let keys = keys
+                .iter()
+                .map(|key| key.parse())
+                .collect::<Result<Vec<Key>, String>>()
+                .map_err(de::Error::custom)?;
+            keymap.bindings.insert(action, keys);
+        }
+
+        Ok(keymap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(s: &str) -> Key {
+        s.parse().unwrap()
+    }
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> event::KeyEvent {
+        event::KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn parses_keys_with_modifiers() {
+        assert_eq!(
+            key("Ctrl+r"),
+            Key {
+                code: KeyCode::Char('r'),
+                modifiers: KeyModifiers::CONTROL
+            }
+        );
+        assert_eq!(
+            key("ctrl+alt+Down"),
+            Key {
+                code: KeyCode::Down,
+                modifiers: KeyModifiers::CONTROL | KeyModifiers::ALT
+            }
+        );
+        assert_eq!(key("F5").code, KeyCode::F(5));
+        assert_eq!(key("+").code, KeyCode::Char('+'));
+        assert_eq!(key("Ctrl++").code, KeyCode::Char('+'));
+        assert_eq!(key("Space").code, KeyCode::Char(' '));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_modifiers() {
+        assert_eq!(
+            "Hyper+r".parse::<Key>(),
+            Err("unknown modifier Hyper in key Hyper+r".to_string())
+        );
+        assert_eq!(
+            "Ctrl+rr".parse::<Key>(),
+            Err("unknown key Ctrl+rr".to_string())
+        );
+        assert!("".parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn matches_ctrl_keys_only_with_ctrl() {
+        let reload = key("Ctrl+r");
+        assert!(reload.matches(&event(KeyCode::Char('r'), KeyModifiers::CONTROL)));
+        assert!(!reload.matches(&event(KeyCode::Char('r'), KeyModifiers::NONE)));
+        assert!(!reload.matches(&event(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        )));
+    }
+
+    #[test]
+    fn matches_shift_with_a_lowercase_letter_like_the_uppercase_letter() {
+        for binding in ["Shift+r", "R", "Shift+R"] {
+            let binding = key(binding);
+            assert!(binding.matches(&event(KeyCode::Char('R'), KeyModifiers::SHIFT)));
+            assert!(binding.matches(&event(KeyCode::Char('R'), KeyModifiers::NONE)));
+            assert!(!binding.matches(&event(KeyCode::Char('r'), KeyModifiers::NONE)));
+        }
+
+        let binding = key("Ctrl+Shift+r");
+        assert!(binding.matches(&event(
+            KeyCode::Char('R'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert!(!binding.matches(&event(KeyCode::Char('r'), KeyModifiers::CONTROL)));
+    }
+}
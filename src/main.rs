@@ -1,3 +1,5 @@
+use std::env;
+use std::fs;
 use std::io;
 
 use ratatui::{backend::CrosstermBackend, Terminal};
@@ -11,16 +13,65 @@ use crate::{
 };
 
 pub mod app;
+pub mod audit;
+pub mod clipboard;
+pub mod codec;
 pub mod event;
 pub mod handler;
+pub mod keymap;
 pub mod settings;
 pub mod theme;
 pub mod tui;
 pub mod widgets;
 
+/// The query to start with from the `--query` argument: either the query itself, `@path` to read
+/// it from a file, or `-` to read it from stdin.
+fn query_arg() -> AppResult<Option<String>> {
+    let mut args = env::args().skip(1);
+    let mut query_arg = None;
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--query=") {
+            query_arg = Some(value.to_string());
+        } else if arg == "--query" {
+            query_arg = Some(
+                args.next()
+                    .ok_or(anyhow::anyhow!("--query requires a query, @file or -"))?,
+            );
+        }
+    }
+
+    let Some(query_arg) = query_arg else {
+        return Ok(None);
+    };
+    let query = if query_arg == "-" {
+        io::read_to_string(io::stdin())
+            .map_err(|e| anyhow::anyhow!("could not read query from stdin: {}", e))?
+    } else if let Some(path) = query_arg.strip_prefix('@') {
+        fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read query from '{}': {}", path, e))?
+    } else {
+        query_arg
+    };
+
+    // Queries kept in files are often split over lines to be readable
+    let query = query
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    Ok(Some(query))
+}
+
 #[tokio::main]
 async fn main() -> AppResult<()> {
-    let settings = Settings::new()?;
+    let mut settings = Settings::new()?;
+    if let Some(query) = query_arg()? {
+        settings.default_query = Some(query);
+    }
+    if env::args().any(|arg| arg == "--read-only") {
+        settings.read_only = true;
+    }
     let level = if settings.debug {
         "debug".to_string()
     } else {
@@ -40,6 +91,11 @@ async fn main() -> AppResult<()> {
         )
         .init();
 
+    audit::init(&settings.audit_log_path, &settings.identity);
+    if let Some(codec_endpoint) = settings.codec_endpoint.as_ref() {
+        codec::init(codec_endpoint, &settings.namespace);
+    }
+
     // Create an application.
     let app = App::new(&settings).await?;
 
@@ -47,6 +103,7 @@ async fn main() -> AppResult<()> {
     let backend = CrosstermBackend::new(io::stdout());
     let terminal = Terminal::new(backend)?;
     let events = EventHandler::new(250);
+    events.watch_theme(settings.clone());
     let tui = Tui::new(terminal, events);
 
     app.run(tui).await?;
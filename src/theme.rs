@@ -18,6 +18,20 @@ pub struct Theme {
     pub failure_background: style::Color,
     pub running_background: style::Color,
     pub cancelled_background: style::Color,
+    /// Colors of indicators that something is at risk or out of date, like an overdue heartbeat.
+    /// Optional in custom themes, which predate them.
+    #[serde(default = "default_warning_foreground")]
+    pub warning_foreground: style::Color,
+    #[serde(default = "default_warning_background")]
+    pub warning_background: style::Color,
+}
+
+fn default_warning_foreground() -> style::Color {
+    NORD_DARK.warning_foreground
+}
+
+fn default_warning_background() -> style::Color {
+    NORD_DARK.warning_background
 }
 
 impl Default for Theme {
@@ -41,6 +55,8 @@ pub const SOLARIZED_DARK_HIGH_CONTRAST: Theme = Theme {
     failure_background: style::Color::from_u32(0x00582b29),
     running_background: style::Color::from_u32(0x00004363),
     cancelled_background: style::Color::from_u32(0x00928374),
+    warning_foreground: style::Color::from_u32(0x00002b36),
+    warning_background: style::Color::from_u32(0x00b58900),
 };
 
 pub const NORD_DARK: Theme = Theme {
@@ -58,4 +74,60 @@ pub const NORD_DARK: Theme = Theme {
     failure_background: style::Color::from_u32(0x00bf616a),
     running_background: style::Color::from_u32(0x005e81ac),
     cancelled_background: style::Color::from_u32(0x004c566a),
+    warning_foreground: style::Color::from_u32(0x002e3440),
+    warning_background: style::Color::from_u32(0x00ebcb8b),
+};
+
+pub const SOLARIZED_LIGHT: Theme = Theme {
+    background: style::Color::from_u32(0x00fdf6e3),
+    foreground: style::Color::from_u32(0x00073642),
+    alt_background: style::Color::from_u32(0x00eee8d5),
+    selection_foreground: style::Color::from_u32(0x00fdf6e3),
+    selection_background: style::Color::from_u32(0x00657b83),
+    header_foreground: style::Color::from_u32(0x00073642),
+    header_background: style::Color::from_u32(0x00eee8d5),
+    footer_foreground: style::Color::from_u32(0x00073642),
+    footer_background: style::Color::from_u32(0x00eee8d5),
+    border: style::Color::from_u32(0x00268bd2),
+    success_background: style::Color::from_u32(0x00d5e0a8),
+    failure_background: style::Color::from_u32(0x00f4c7c3),
+    running_background: style::Color::from_u32(0x00c5dcef),
+    cancelled_background: style::Color::from_u32(0x00d8d3c2),
+    warning_foreground: style::Color::from_u32(0x00073642),
+    warning_background: style::Color::from_u32(0x00f2d68b),
+};
+
+pub const GRUVBOX_DARK: Theme = Theme {
+    background: style::Color::from_u32(0x00282828),
+    foreground: style::Color::from_u32(0x00ebdbb2),
+    alt_background: style::Color::from_u32(0x003c3836),
+    selection_foreground: style::Color::from_u32(0x00282828),
+    selection_background: style::Color::from_u32(0x00d5c4a1),
+    header_foreground: style::Color::from_u32(0x00ebdbb2),
+    header_background: style::Color::from_u32(0x00282828),
+    footer_foreground: style::Color::from_u32(0x00ebdbb2),
+    footer_background: style::Color::from_u32(0x00282828),
+    border: style::Color::from_u32(0x0083a598),
+    success_background: style::Color::from_u32(0x0079740e),
+    failure_background: style::Color::from_u32(0x009d0006),
+    running_background: style::Color::from_u32(0x00076678),
+    cancelled_background: style::Color::from_u32(0x00665c54),
+    warning_foreground: style::Color::from_u32(0x00282828),
+    warning_background: style::Color::from_u32(0x00d79921),
 };
+
+/// The built-in themes, by the name they are picked with in the settings.
+pub const THEMES: &[(&str, Theme)] = &[
+    ("nord_dark", NORD_DARK),
+    ("solarized_dark_high_contrast", SOLARIZED_DARK_HIGH_CONTRAST),
+    ("solarized_light", SOLARIZED_LIGHT),
+    ("gruvbox_dark", GRUVBOX_DARK),
+];
+
+/// The built-in theme called `name`, ignoring case.
+pub fn by_name(name: &str) -> Option<Theme> {
+    THEMES
+        .iter()
+        .find(|(theme_name, _)| theme_name.eq_ignore_ascii_case(name))
+        .map(|(_, theme)| *theme)
+}
@@ -1,14 +1,132 @@
+use std::collections;
+use std::future;
+use std::sync;
 use std::time;
 
 use crate::theme::Theme;
-use ratatui::style;
-use temporal_sdk_core_protos::temporal::api::{enums::v1 as enums, workflow::v1 as workflow};
+use ratatui::{buffer, layout, style, style::Stylize, widgets};
+use temporal_sdk_core_protos::temporal::api::{
+    common::v1 as common, enums::v1 as enums, workflow::v1 as workflow,
+};
+use tokio::task;
 
 pub struct Keybind {
     keys: Vec<String>,
     operation: String,
 }
 
+/// Shorten `value` to `width` characters by replacing its middle with an ellipsis, keeping both
+/// the prefix and the suffix, which often tell IDs apart, visible.
+pub fn truncate_middle(value: &str, width: usize) -> String {
+    let length = value.chars().count();
+    if length <= width {
+        return value.to_owned();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let kept = width - 1;
+    let suffix_length = kept / 2;
+    let prefix_length = kept - suffix_length;
+    let prefix: String = value.chars().take(prefix_length).collect();
+    let suffix: String = value.chars().skip(length - suffix_length).collect();
+    format!("{}…{}", prefix, suffix)
+}
+
+/// How long the notice that something was copied to the clipboard is displayed for.
+pub const COPIED_NOTICE_DURATION: time::Duration = time::Duration::from_secs(2);
+
+/// The least time between two reloads of a view, so that requesting many in a row doesn't
+/// flood the server.
+pub const MIN_RELOAD_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
+/// Whether a reload of a view was requested and hasn't started yet, so more requests are
+/// collapsed into it.
+#[derive(Debug, Clone, Default)]
+pub struct ReloadQueue(sync::Arc<sync::RwLock<bool>>);
+
+impl ReloadQueue {
+    /// Queue a reload, unless one is already waiting to start. Returns whether it was queued.
+    pub fn push(&self) -> bool {
+        let mut queued = self.0.write().unwrap();
+        !std::mem::replace(&mut *queued, true)
+    }
+
+    /// Wait until at least [`MIN_RELOAD_INTERVAL`] has passed since `last_reload`, then let more
+    /// reloads be queued.
+    pub async fn wait(&self, last_reload: &sync::RwLock<Option<tokio::time::Instant>>) {
+        let since_last_reload = last_reload.read().unwrap().map(|i| i.elapsed());
+        if let Some(since_last_reload) =
+            since_last_reload.filter(|elapsed| *elapsed < MIN_RELOAD_INTERVAL)
+        {
+            tokio::time::sleep(MIN_RELOAD_INTERVAL - since_last_reload).await;
+        }
+        *self.0.write().unwrap() = false;
+    }
+}
+
+/// Format `duration` with its two most significant units, like "2h 0m" or "1d 3h".
+pub fn humanize_duration(duration: time::Duration) -> String {
+    let seconds = duration.as_secs();
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60);
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds % 60)
+    } else if seconds > 0 {
+        format!("{}s", seconds)
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
+}
+
+/// Like [`humanize_duration`], for optional durations like those in Temporal protos.
+pub fn humanize_optional_duration<D: TryInto<time::Duration>>(duration: Option<D>) -> String {
+    match duration.and_then(|d| d.try_into().ok()) {
+        Some(duration) => humanize_duration(duration),
+        None => "-".to_owned(),
+    }
+}
+
+/// `value`, like a Temporal proto received from the server, as indented JSON.
+pub fn to_pretty_json(value: &impl serde::Serialize) -> String {
+    serde_json::to_string_pretty(value)
+        .unwrap_or_else(|e| format!("<not representable as JSON: {}>", e))
+}
+
+/// How timestamps are displayed, either as dates or as the time elapsed since.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    #[default]
+    Absolute,
+    Relative,
+}
+
+impl TimeFormat {
+    pub fn toggle(self) -> Self {
+        match self {
+            TimeFormat::Absolute => TimeFormat::Relative,
+            TimeFormat::Relative => TimeFormat::Absolute,
+        }
+    }
+
+    /// Format `dt`, like "24-05-01 12:00:00 UTC" or "3m 12s ago".
+    pub fn format(self, dt: chrono::DateTime<chrono::Utc>) -> String {
+        match self {
+            TimeFormat::Absolute => format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")),
+            TimeFormat::Relative => match (chrono::Utc::now() - dt).to_std() {
+                Ok(elapsed) => format!("{} ago", humanize_duration(elapsed)),
+                // Clocks may disagree slightly about very recent events
+                Err(_) => "just now".to_owned(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum LoadingState {
     #[default]
@@ -19,10 +137,144 @@ pub enum LoadingState {
     Error(String),
 }
 
+impl LoadingState {
+    /// Whether an operation is in flight.
+    pub fn is_busy(&self) -> bool {
+        matches!(self, LoadingState::Loading)
+    }
+
+    /// What to tell the user while an operation is in flight.
+    pub fn busy_message(&self) -> Option<String> {
+        match self {
+            LoadingState::Loading => Some("Busy...".to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Render a banner telling that a request failed, above the data loaded before it.
+pub fn render_error_banner(
+    message: &str,
+    theme: Theme,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let banner = widgets::Paragraph::new(format!("{} (Esc to dismiss, Ctrl+r to retry)", message))
+        .fg(theme.foreground)
+        .bg(theme.failure_background);
+    widgets::Widget::render(banner, area, buf);
+}
+
+/// The background tasks a widget spawns. Each task holds a clone of the widget, and with it the
+/// widget's client and sender, so they never end on their own: they have to be shut down once the
+/// widget is no longer displayed.
+#[derive(Debug, Clone, Default)]
+pub struct BackgroundTasks(sync::Arc<sync::Mutex<task::JoinSet<()>>>);
+
+impl BackgroundTasks {
+    /// Spawn `task`, returning a handle to abort it on its own.
+    pub fn spawn<F>(&self, task: F) -> task::AbortHandle
+    where
+        F: future::Future<Output = ()> + Send + 'static,
+    {
+        let mut tasks = self.0.lock().unwrap();
+        // Forget about the tasks that already finished, like heartbeat polls
+        while tasks.try_join_next().is_some() {}
+        tasks.spawn(task)
+    }
+
+    /// Abort all tasks, dropping their clones of the widget.
+    pub fn shutdown(&self) {
+        let mut tasks = self.0.lock().unwrap();
+        if !tasks.is_empty() {
+            log::debug!(tasks = tasks.len(); "Aborting background tasks");
+        }
+        tasks.abort_all();
+    }
+}
+
 #[derive(Debug)]
 pub enum Message {
     Reload,
-    LoadPage { page_token: Vec<u8> },
+    LoadPage {
+        page_token: Vec<u8>,
+    },
+    Describe,
+    /// Load why a terminated or canceled workflow was closed.
+    LoadCloseReason {
+        workflow_id: String,
+        run_id: String,
+    },
+    /// Describe a workflow to preview it.
+    LoadPreview {
+        workflow_id: String,
+        run_id: String,
+    },
+    /// Request cancelling the workflow, for the given reason.
+    Cancel {
+        reason: String,
+    },
+    /// Switch to a new theme, like when it's changed in the settings.
+    SetTheme(Theme),
+    /// Send a signal to the workflow, with a JSON `input` if any.
+    Signal {
+        name: String,
+        input: Option<String>,
+    },
+    /// Query the workflow's stack trace with the built-in `__stack_trace` query.
+    StackTrace,
+}
+
+/// Where to move the selection once the next page arrives, when a key moved it past the loaded
+/// rows. The background task loads the page and moves the selection, so keys aren't held up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingJump {
+    NextRow,
+    NextPage,
+    /// Select the last row once every page is loaded, one after the other.
+    LastRow,
+}
+
+/// A point in a workflow's history it can be reset to.
+#[derive(Debug, Default, Clone)]
+pub struct ResetPoint {
+    pub build_id: String,
+    pub binary_checksum: String,
+    pub run_id: String,
+    pub first_workflow_task_completed_id: i64,
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub expire_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub resettable: bool,
+}
+
+impl ResetPoint {
+    /// The build ID of the worker that completed the workflow task, or its binary checksum for
+    /// older workers.
+    pub fn build_id_or_checksum(&self) -> &str {
+        if self.build_id.is_empty() {
+            &self.binary_checksum
+        } else {
+            &self.build_id
+        }
+    }
+}
+
+impl From<workflow::ResetPointInfo> for ResetPoint {
+    fn from(info: workflow::ResetPointInfo) -> Self {
+        Self {
+            build_id: info.build_id,
+            binary_checksum: info.binary_checksum,
+            run_id: info.run_id,
+            first_workflow_task_completed_id: info.first_workflow_task_completed_id,
+            create_time: info
+                .create_time
+                .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
+            expire_time: info
+                .expire_time
+                .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
+            resettable: info.resettable,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -37,6 +289,12 @@ pub struct WorkflowExecution {
     pub execution_time: Option<chrono::DateTime<chrono::Utc>>,
     pub execution_duration: Option<time::Duration>,
     pub history_size_bytes: u64,
+    pub history_length: u64,
+    pub reset_points: Vec<ResetPoint>,
+    /// Memo fields by name, which can't be queried.
+    pub memo: collections::BTreeMap<String, serde_json::Value>,
+    /// Search attributes by name, which are indexed to query workflows with.
+    pub search_attributes: collections::BTreeMap<String, serde_json::Value>,
 }
 
 impl WorkflowExecution {
@@ -63,7 +321,27 @@ impl WorkflowExecution {
 
     pub fn execution_duration_as_string(&self) -> String {
         match self.execution_duration {
-            Some(dur) => format!("{}s", dur.as_secs()),
+            Some(dur) => humanize_duration(dur),
+            None => "-".to_owned(),
+        }
+    }
+
+    /// Time left until a closed workflow is deleted after `retention`, negative if it's past
+    /// due. Open workflows are not subject to retention.
+    pub fn retention_remaining(&self, retention: time::Duration) -> Option<chrono::TimeDelta> {
+        let close_time = self.close_time?;
+        let deleted_at = close_time + chrono::TimeDelta::from_std(retention).ok()?;
+        Some(deleted_at - chrono::Utc::now())
+    }
+
+    pub fn retention_remaining_as_string(&self, retention: time::Duration) -> String {
+        match self.retention_remaining(retention) {
+            Some(remaining) if remaining <= chrono::TimeDelta::zero() => "Expired".to_owned(),
+            Some(remaining) => format!(
+                "{} (of {})",
+                humanize_duration(remaining.to_std().unwrap_or_default()),
+                humanize_duration(retention)
+            ),
             None => "-".to_owned(),
         }
     }
@@ -95,6 +373,22 @@ impl WorkflowExecution {
     }
 }
 
+/// Decode each of `fields` as JSON, like SDKs encode memos and search attributes, or as text if
+/// it isn't JSON.
+fn decode_fields(
+    fields: collections::HashMap<String, common::Payload>,
+) -> collections::BTreeMap<String, serde_json::Value> {
+    fields
+        .into_iter()
+        .map(|(name, payload)| {
+            let value = serde_json::from_slice(&payload.data).unwrap_or_else(|_| {
+                serde_json::Value::String(String::from_utf8_lossy(&payload.data).into_owned())
+            });
+            (name, value)
+        })
+        .collect()
+}
+
 impl TryFrom<workflow::WorkflowExecutionInfo> for WorkflowExecution {
     type Error = anyhow::Error;
 
@@ -135,6 +429,83 @@ impl TryFrom<workflow::WorkflowExecutionInfo> for WorkflowExecution {
             }),
             execution_duration,
             history_size_bytes: execution_info.history_size_bytes as u64,
+            history_length: execution_info.history_length as u64,
+            reset_points: execution_info
+                .auto_reset_points
+                .map(|reset_points| {
+                    reset_points
+                        .points
+                        .into_iter()
+                        .map(ResetPoint::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            memo: execution_info
+                .memo
+                .map(|memo| decode_fields(memo.fields))
+                .unwrap_or_default(),
+            search_attributes: execution_info
+                .search_attributes
+                .map(|search_attributes| decode_fields(search_attributes.indexed_fields))
+                .unwrap_or_default(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_duration_keeps_the_two_most_significant_units() {
+        assert_eq!(
+            humanize_duration(time::Duration::from_secs(90_000)),
+            "1d 1h"
+        );
+        assert_eq!(humanize_duration(time::Duration::from_secs(7200)), "2h 0m");
+        assert_eq!(humanize_duration(time::Duration::from_secs(61)), "1m 1s");
+        assert_eq!(humanize_duration(time::Duration::from_secs(59)), "59s");
+    }
+
+    #[test]
+    fn humanize_duration_shows_milliseconds_under_a_second() {
+        assert_eq!(humanize_duration(time::Duration::from_millis(1500)), "1s");
+        assert_eq!(humanize_duration(time::Duration::from_millis(250)), "250ms");
+        assert_eq!(humanize_duration(time::Duration::ZERO), "0ms");
+    }
+
+    #[test]
+    fn truncate_middle_keeps_values_that_fit() {
+        assert_eq!(truncate_middle("workflow", 8), "workflow");
+        assert_eq!(truncate_middle("workflow", 20), "workflow");
+        assert_eq!(truncate_middle("", 0), "");
+    }
+
+    #[test]
+    fn truncate_middle_keeps_both_ends() {
+        assert_eq!(truncate_middle("workflow-id", 7), "wor…-id");
+        assert_eq!(truncate_middle("workflow-id", 1), "…");
+        assert_eq!(truncate_middle("workflow-id", 0), "");
+    }
+
+    #[test]
+    fn truncate_middle_counts_characters_not_bytes() {
+        assert_eq!(truncate_middle("ñandú-año", 9), "ñandú-año");
+        assert_eq!(truncate_middle("ñandú-año", 5), "ña…ño");
+    }
+
+    #[test]
+    fn retention_under_a_day_is_not_expired_early() {
+        let workflow_execution = WorkflowExecution {
+            close_time: Some(chrono::Utc::now() - chrono::TimeDelta::hours(1)),
+            ..WorkflowExecution::default()
+        };
+        let retention = time::Duration::from_secs(12 * 60 * 60);
+
+        let remaining = workflow_execution.retention_remaining(retention).unwrap();
+        assert!(remaining > chrono::TimeDelta::hours(10));
+        assert!(workflow_execution
+            .retention_remaining_as_string(retention)
+            .ends_with("(of 12h 0m)"));
+    }
+}
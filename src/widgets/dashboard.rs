@@ -0,0 +1,245 @@
+use std::cmp;
+use std::sync;
+
+use crossterm::event;
+use ratatui::{buffer, layout, style, style::Stylize, text, widgets};
+use temporal_client::{self, WorkflowClientTrait};
+use temporal_sdk_core_protos::temporal::api::workflowservice::v1 as service;
+use tokio::sync::mpsc;
+
+use crate::keymap::Action;
+use crate::settings::Settings;
+use crate::theme::Theme;
+use crate::widgets::common::{BackgroundTasks, LoadingState, Message};
+use crate::widgets::workflow_table::WorkflowTableWidget;
+use crate::widgets::{Keybindable, Navigation, ViewWidget};
+
+/// The total count of workflows, and the count for each status ordered from the largest.
+type Counts = (i64, Vec<(String, i64)>);
+
+/// Counts of the workflows matching a query, grouped by their status, for a quick look at the
+/// health of many workflows at once.
+#[derive(Debug, Clone)]
+pub struct DashboardWidget {
+    temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+    sender: sync::Arc<Option<mpsc::Sender<Message>>>,
+    tasks: BackgroundTasks,
+    theme: Theme,
+    settings: sync::Arc<Settings>,
+    /// The Visibility query of the workflows to count.
+    query: String,
+    /// The table we came from, returned to with the same query and filters.
+    workflow_table: WorkflowTableWidget,
+    counts: sync::Arc<sync::RwLock<Option<Counts>>>,
+    loading_state: sync::Arc<sync::RwLock<LoadingState>>,
+}
+
+/// The color of a status, as returned in the group values of a count response.
+fn status_color(status: &str, theme: Theme) -> style::Color {
+    match status {
+        "Running" => theme.running_background,
+        "Completed" => theme.success_background,
+        "Failed" | "Terminated" | "TimedOut" => theme.failure_background,
+        _ => theme.cancelled_background,
+    }
+}
+
+impl DashboardWidget {
+    pub fn new(
+        temporal_client: &sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+        workflow_table: WorkflowTableWidget,
+        settings: &sync::Arc<Settings>,
+        query: &str,
+        theme: Theme,
+    ) -> Self {
+        Self {
+            temporal_client: temporal_client.clone(),
+            sender: sync::Arc::new(None),
+            tasks: BackgroundTasks::default(),
+            theme,
+            settings: settings.clone(),
+            query: query.to_owned(),
+            workflow_table,
+            counts: sync::Arc::new(sync::RwLock::new(None)),
+            loading_state: sync::Arc::new(sync::RwLock::new(LoadingState::Idle)),
+        }
+    }
+
+    /// A short name for the tab showing this dashboard.
+    pub fn tab_title(&self) -> String {
+        match self.query.as_str() {
+            "" => "Counts".to_string(),
+            query => format!("Counts {}", query),
+        }
+    }
+
+    pub fn run(&mut self) {
+        let (tx, rx) = mpsc::channel(32);
+        *sync::Arc::get_mut(&mut self.sender).unwrap() = Some(tx);
+
+        let this = self.clone(); // clone the widget to pass to the background task
+        self.tasks.spawn(this.fetch_counts(rx));
+    }
+
+    async fn fetch_counts(mut self, mut receiver: mpsc::Receiver<Message>) {
+        log::debug!(widget = "DashboardWidget"; "Starting fetch_counts loop");
+        while let Some(message) = receiver.recv().await {
+            if let Message::Reload = message {
+                log::debug!(widget = "DashboardWidget"; "Reloading");
+                self.set_loading_state(LoadingState::Loading);
+
+                let query = match self.query.as_str() {
+                    "" => "GROUP BY ExecutionStatus".to_string(),
+                    query => format!("{} GROUP BY ExecutionStatus", query),
+                };
+                let count_result = self.temporal_client.count_workflow_executions(query).await;
+
+                match count_result {
+                    Ok(response) => {
+                        self.on_counts_load(response);
+                        self.set_loading_state(LoadingState::Reloaded);
+                    }
+                    Err(e) => self.set_loading_state(LoadingState::Error(format!(
+                        "count workflow executions request failed: {}",
+                        e
+                    ))),
+                }
+            }
+        }
+    }
+
+    fn on_counts_load(&mut self, response: service::CountWorkflowExecutionsResponse) {
+        let mut groups: Vec<(String, i64)> = response
+            .groups
+            .into_iter()
+            .map(|group| {
+                let status = group
+                    .group_values
+                    .iter()
+                    .map(|value| {
+                        serde_json::from_slice::<String>(&value.data)
+                            .unwrap_or(String::from_utf8_lossy(&value.data).into_owned())
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                (status, group.count)
+            })
+            .collect();
+        groups.sort_by_key(|(_, count)| cmp::Reverse(*count));
+
+        *self.counts.write().unwrap() = Some((response.count, groups));
+    }
+
+    fn set_loading_state(&mut self, loading_state: LoadingState) {
+        *self.loading_state.write().unwrap() = loading_state;
+    }
+
+    pub async fn reload(&self) {
+        let sender = self.sender.as_ref().clone();
+        sender.unwrap().send(Message::Reload).await.unwrap();
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.workflow_table.set_theme(theme);
+    }
+
+    /// Stop loading in the background, once this widget is no longer displayed.
+    pub fn shutdown(&self) {
+        self.tasks.shutdown();
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading_state.read().unwrap().is_busy()
+    }
+
+    pub fn busy_message(&self) -> Option<String> {
+        self.loading_state.read().unwrap().busy_message()
+    }
+}
+
+impl Keybindable for DashboardWidget {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        match key {
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
+            key if self.settings.keybinds.matches(Action::Back, &key) => {
+                return Some(Navigation::Back(ViewWidget::WorkflowTable(
+                    self.workflow_table.duplicate(),
+                )));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn keybinds(&self) -> &[(&str, &[&str])] {
+        &[("Previous view", &["Esc"]), ("Reload", &["Ctrl+r"])]
+    }
+}
+
+impl widgets::Widget for &DashboardWidget {
+    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title(
+                text::Line::from(match self.query.as_str() {
+                    "" => "Workflows by status".to_string(),
+                    query => format!("Workflows by status ({})", query),
+                })
+                .fg(self.theme.header_foreground)
+                .bold(),
+            )
+            .border_style(style::Style::new().fg(self.theme.border))
+            .bg(self.theme.background);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let counts = self.counts.read().unwrap();
+        let (total, groups) = match (counts.as_ref(), &*self.loading_state.read().unwrap()) {
+            (_, LoadingState::Error(e)) => {
+                widgets::Paragraph::new(e.clone())
+                    .fg(self.theme.foreground)
+                    .render(inner_area, buf);
+                return;
+            }
+            (Some((total, groups)), _) => (*total, groups),
+            (None, _) => {
+                widgets::Paragraph::new("Loading counts...")
+                    .fg(self.theme.foreground)
+                    .render(inner_area, buf);
+                return;
+            }
+        };
+
+        let [total_area, chart_area] =
+            layout::Layout::vertical([layout::Constraint::Length(2), layout::Constraint::Fill(1)])
+                .areas(inner_area);
+
+        widgets::Paragraph::new(format!("Total: {}", total))
+            .fg(self.theme.foreground)
+            .render(total_area, buf);
+
+        let bars: Vec<widgets::Bar> = groups
+            .iter()
+            .map(|(status, count)| {
+                widgets::Bar::default()
+                    .label(text::Line::from(status.clone()))
+                    .value(*count as u64)
+                    .text_value(count.to_string())
+                    .style(style::Style::new().fg(status_color(status, self.theme)))
+                    .value_style(
+                        style::Style::new()
+                            .fg(self.theme.foreground)
+                            .bg(status_color(status, self.theme)),
+                    )
+            })
+            .collect();
+
+        widgets::BarChart::default()
+            .direction(layout::Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(1)
+            .data(widgets::BarGroup::default().bars(&bars))
+            .render(chart_area, buf);
+    }
+}
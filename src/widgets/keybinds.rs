@@ -1,6 +1,7 @@
 use crossterm::event;
 use ratatui::{buffer, layout, style, text, widgets};
 
+use crate::keymap::KeyMap;
 use crate::theme::Theme;
 
 #[derive(Debug, Clone)]
@@ -20,6 +21,39 @@ impl KeybindsWidget {
         }
     }
 
+    /// Show the keys configured in `keymap` for the actions that were rebound.
+    pub fn with_keymap(mut self, keymap: &KeyMap) -> Self {
+        for (action, keys) in self.inner.iter_mut() {
+            let default_keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            if let Some(rebound) = keymap.rebound_keys(action, &default_keys) {
+                *keys = rebound;
+            }
+        }
+        self
+    }
+
+    /// One line for each keybind, with the keys aligned in a column, for listing them all.
+    pub fn to_lines(&self) -> Vec<text::Line<'static>> {
+        let keys: Vec<String> = self.inner.iter().map(|(_, keys)| keys.join("/")).collect();
+        let keys_width = keys.iter().map(|k| k.chars().count()).max().unwrap_or(0);
+
+        self.inner
+            .iter()
+            .zip(keys)
+            .map(|((action, _), keys)| {
+                text::Line::from(vec![
+                    text::Span::from(" ".repeat(keys_width - keys.chars().count())),
+                    text::Span::from(keys).style(
+                        style::Style::new()
+                            .fg(self.theme.selection_foreground)
+                            .bg(self.theme.selection_background),
+                    ),
+                    text::Span::from(format!("  {}", action)),
+                ])
+            })
+            .collect()
+    }
+
     pub fn push(&mut self, keybind: (&'_ str, &'_ [&'_ str])) {
         self.inner.push((
             keybind.0.to_string(),
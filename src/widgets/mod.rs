@@ -1,14 +1,36 @@
 use crossterm::event;
 use ratatui::{buffer, layout, widgets};
 
+use crate::theme::Theme;
+
 mod common;
+pub mod dashboard;
 pub mod keybinds;
+pub mod namespace;
+mod query;
+pub mod task_queue;
 pub mod workflow;
 pub mod workflow_table;
 
 pub trait Keybindable {
-    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<ViewWidget>;
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation>;
     fn keybinds<'k>(&'k self) -> &'k [(&'k str, &'k [&'k str])];
+
+    /// Keybinds of actions that change workflows, listed apart from [`Keybindable::keybinds`] to
+    /// hide them in read-only mode.
+    fn write_keybinds(&self) -> &[(&str, &[&str])] {
+        &[]
+    }
+}
+
+/// How the [`App`] changes views after a view handled a key.
+#[derive(Debug)]
+pub enum Navigation {
+    /// Open a view over the current one, which is kept as it is to go back to.
+    Push(ViewWidget),
+    /// Go back to the view the current one was opened from, or to a new `fallback` view when it
+    /// wasn't opened from another one.
+    Back(ViewWidget),
 }
 
 /// Enumeration of potential views the [`App`] can display.
@@ -18,6 +40,12 @@ pub enum ViewWidget {
     Workflow(workflow::WorkflowWidget),
     /// A view of all Temporal workflow executions rendered by [`WorkflowTableWidget`].
     WorkflowTable(workflow_table::WorkflowTableWidget),
+    /// A view of the configuration of the namespace we are connected to.
+    Namespace(namespace::NamespaceWidget),
+    /// A view of how many workflows match a query, by status.
+    Dashboard(dashboard::DashboardWidget),
+    /// A view of the workers polling a task queue.
+    TaskQueue(task_queue::TaskQueueWidget),
 }
 
 impl ViewWidget {
@@ -31,6 +59,100 @@ impl ViewWidget {
                 workflow.run();
                 workflow.reload().await;
             }
+            ViewWidget::Namespace(namespace) => {
+                namespace.run();
+                namespace.reload().await;
+            }
+            ViewWidget::Dashboard(dashboard) => {
+                dashboard.run();
+                dashboard.reload().await;
+            }
+            ViewWidget::TaskQueue(task_queue) => {
+                task_queue.run();
+                task_queue.reload().await;
+            }
+        }
+    }
+
+    /// Stop the background tasks of this view, so they drop their clients before it's replaced.
+    pub fn shutdown(&self) {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.shutdown(),
+            ViewWidget::Workflow(workflow) => workflow.shutdown(),
+            ViewWidget::Namespace(namespace) => namespace.shutdown(),
+            ViewWidget::Dashboard(dashboard) => dashboard.shutdown(),
+            ViewWidget::TaskQueue(task_queue) => task_queue.shutdown(),
+        }
+    }
+
+    /// Switch to `theme`, like when it's changed in the settings.
+    pub async fn set_theme(&mut self, theme: Theme) {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.set_theme(theme),
+            ViewWidget::Workflow(workflow) => workflow.switch_theme(theme).await,
+            ViewWidget::Namespace(namespace) => namespace.set_theme(theme),
+            ViewWidget::Dashboard(dashboard) => dashboard.set_theme(theme),
+            ViewWidget::TaskQueue(task_queue) => task_queue.set_theme(theme),
+        }
+    }
+
+    /// Tell the view whether it's being reloaded automatically, so it can show it.
+    pub fn set_auto_refresh(&mut self, auto_refresh: bool) {
+        if let ViewWidget::WorkflowTable(workflow_table) = self {
+            workflow_table.set_auto_refresh(auto_refresh);
+        }
+    }
+
+    /// The action that changes a workflow `key` would start in this view, if any, to refuse it in
+    /// read-only mode.
+    pub fn write_action(&self, key: event::KeyEvent) -> Option<&'static str> {
+        match self {
+            ViewWidget::Workflow(workflow) => workflow.write_action(key),
+            _ => None,
+        }
+    }
+
+    /// Whether keys are being typed into an input of this view, so they shouldn't be taken as
+    /// keybinds.
+    pub fn is_typing(&self) -> bool {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.is_querying(),
+            ViewWidget::Workflow(workflow) => workflow.is_typing(),
+            _ => false,
+        }
+    }
+
+    /// A short name for the tab showing this view.
+    pub fn tab_title(&self) -> String {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.tab_title(),
+            ViewWidget::Workflow(workflow) => workflow.tab_title(),
+            ViewWidget::Namespace(namespace) => namespace.tab_title(),
+            ViewWidget::Dashboard(dashboard) => dashboard.tab_title(),
+            ViewWidget::TaskQueue(task_queue) => task_queue.tab_title(),
+        }
+    }
+
+    /// Whether the current view has an operation in flight, so it shouldn't start actions that
+    /// change workflows.
+    pub fn is_busy(&self) -> bool {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.is_loading(),
+            ViewWidget::Workflow(workflow) => workflow.is_loading(),
+            ViewWidget::Namespace(namespace) => namespace.is_loading(),
+            ViewWidget::Dashboard(dashboard) => dashboard.is_loading(),
+            ViewWidget::TaskQueue(task_queue) => task_queue.is_loading(),
+        }
+    }
+
+    /// What to tell the user while the current view is busy.
+    pub fn busy_message(&self) -> Option<String> {
+        match self {
+            ViewWidget::WorkflowTable(workflow_table) => workflow_table.busy_message(),
+            ViewWidget::Workflow(workflow) => workflow.busy_message(),
+            ViewWidget::Namespace(namespace) => namespace.busy_message(),
+            ViewWidget::Dashboard(dashboard) => dashboard.busy_message(),
+            ViewWidget::TaskQueue(task_queue) => task_queue.busy_message(),
         }
     }
 }
@@ -40,15 +162,21 @@ impl widgets::Widget for &ViewWidget {
         match self {
             ViewWidget::Workflow(w) => w.render(area, buf),
             ViewWidget::WorkflowTable(t) => t.render(area, buf),
+            ViewWidget::Namespace(n) => n.render(area, buf),
+            ViewWidget::Dashboard(d) => d.render(area, buf),
+            ViewWidget::TaskQueue(q) => q.render(area, buf),
         }
     }
 }
 
 impl Keybindable for ViewWidget {
-    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<ViewWidget> {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
         match self {
             ViewWidget::Workflow(w) => w.handle_key(key).await,
             ViewWidget::WorkflowTable(t) => t.handle_key(key).await,
+            ViewWidget::Namespace(n) => n.handle_key(key).await,
+            ViewWidget::Dashboard(d) => d.handle_key(key).await,
+            ViewWidget::TaskQueue(q) => q.handle_key(key).await,
         }
     }
 
@@ -56,6 +184,16 @@ impl Keybindable for ViewWidget {
         match self {
             ViewWidget::Workflow(w) => w.keybinds(),
             ViewWidget::WorkflowTable(t) => t.keybinds(),
+            ViewWidget::Namespace(n) => n.keybinds(),
+            ViewWidget::Dashboard(d) => d.keybinds(),
+            ViewWidget::TaskQueue(q) => q.keybinds(),
+        }
+    }
+
+    fn write_keybinds(&self) -> &[(&str, &[&str])] {
+        match self {
+            ViewWidget::Workflow(w) => w.write_keybinds(),
+            _ => &[],
         }
     }
 }
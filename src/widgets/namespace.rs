@@ -0,0 +1,245 @@
+use std::sync;
+
+use crossterm::event;
+use ratatui::{buffer, layout, style, style::Stylize, text, widgets};
+use temporal_client::{self, WorkflowClientTrait};
+use temporal_sdk_core_protos::temporal::api::{enums::v1 as enums, workflowservice::v1 as service};
+use tokio::sync::mpsc;
+
+use crate::keymap::Action;
+use crate::settings::Settings;
+use crate::theme::Theme;
+use crate::widgets::common::{humanize_optional_duration, BackgroundTasks, LoadingState, Message};
+use crate::widgets::workflow_table::WorkflowTableWidget;
+use crate::widgets::{Keybindable, Navigation, ViewWidget};
+
+/// A read-only view of the configuration of the namespace we are connected to, like its
+/// retention period and archival state.
+#[derive(Debug, Clone)]
+pub struct NamespaceWidget {
+    temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+    sender: sync::Arc<Option<mpsc::Sender<Message>>>,
+    tasks: BackgroundTasks,
+    theme: Theme,
+    settings: sync::Arc<Settings>,
+    namespace: sync::Arc<sync::RwLock<Option<service::DescribeNamespaceResponse>>>,
+    loading_state: sync::Arc<sync::RwLock<LoadingState>>,
+}
+
+fn archival_state_as_str(state: i32) -> &'static str {
+    match enums::ArchivalState::try_from(state) {
+        Ok(enums::ArchivalState::Enabled) => "Enabled",
+        Ok(enums::ArchivalState::Disabled) => "Disabled",
+        _ => "Unspecified",
+    }
+}
+
+fn namespace_state_as_str(state: i32) -> &'static str {
+    match enums::NamespaceState::try_from(state) {
+        Ok(enums::NamespaceState::Registered) => "Registered",
+        Ok(enums::NamespaceState::Deprecated) => "Deprecated",
+        Ok(enums::NamespaceState::Deleted) => "Deleted",
+        _ => "Unspecified",
+    }
+}
+
+impl NamespaceWidget {
+    pub fn new(
+        temporal_client: &sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+        settings: &sync::Arc<Settings>,
+        theme: Theme,
+    ) -> Self {
+        Self {
+            temporal_client: temporal_client.clone(),
+            sender: sync::Arc::new(None),
+            tasks: BackgroundTasks::default(),
+            theme,
+            settings: settings.clone(),
+            namespace: sync::Arc::new(sync::RwLock::new(None)),
+            loading_state: sync::Arc::new(sync::RwLock::new(LoadingState::Idle)),
+        }
+    }
+
+    /// A short name for the tab showing this namespace.
+    pub fn tab_title(&self) -> String {
+        format!("Namespace {}", self.temporal_client.namespace())
+    }
+
+    pub fn run(&mut self) {
+        let (tx, rx) = mpsc::channel(32);
+        *sync::Arc::get_mut(&mut self.sender).unwrap() = Some(tx);
+
+        let this = self.clone(); // clone the widget to pass to the background task
+        self.tasks.spawn(this.fetch_namespace(rx));
+    }
+
+    async fn fetch_namespace(mut self, mut receiver: mpsc::Receiver<Message>) {
+        log::debug!(widget = "NamespaceWidget"; "Starting fetch_namespace loop");
+        while let Some(message) = receiver.recv().await {
+            if let Message::Reload = message {
+                log::debug!(widget = "NamespaceWidget"; "Reloading");
+                self.set_loading_state(LoadingState::Loading);
+
+                let describe_namespace_result = self
+                    .temporal_client
+                    .describe_namespace(temporal_client::Namespace::Name(
+                        self.temporal_client.namespace().to_string(),
+                    ))
+                    .await;
+
+                match describe_namespace_result {
+                    Ok(response) => {
+                        *self.namespace.write().unwrap() = Some(response);
+                        self.set_loading_state(LoadingState::Reloaded);
+                    }
+                    Err(e) => self.set_loading_state(LoadingState::Error(format!(
+                        "describe namespace request failed: {}",
+                        e
+                    ))),
+                }
+            }
+        }
+    }
+
+    fn set_loading_state(&mut self, loading_state: LoadingState) {
+        *self.loading_state.write().unwrap() = loading_state;
+    }
+
+    pub async fn reload(&self) {
+        let sender = self.sender.as_ref().clone();
+        sender.unwrap().send(Message::Reload).await.unwrap();
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Stop loading in the background, once this widget is no longer displayed.
+    pub fn shutdown(&self) {
+        self.tasks.shutdown();
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading_state.read().unwrap().is_busy()
+    }
+
+    pub fn busy_message(&self) -> Option<String> {
+        self.loading_state.read().unwrap().busy_message()
+    }
+
+    fn lines(&self, response: &service::DescribeNamespaceResponse) -> Vec<text::Line<'static>> {
+        let field = |name: &str, value: String| {
+            text::Line::from(vec![
+                text::Span::from(format!("{}: ", name)).fg(self.theme.header_foreground),
+                text::Span::from(value),
+            ])
+        };
+        let or_dash = |value: &str| {
+            if value.is_empty() {
+                "-".to_owned()
+            } else {
+                value.to_owned()
+            }
+        };
+
+        let mut lines = Vec::new();
+        if let Some(info) = response.namespace_info.as_ref() {
+            lines.push(field("Name", info.name.clone()));
+            lines.push(field("ID", info.id.clone()));
+            lines.push(field(
+                "State",
+                namespace_state_as_str(info.state).to_owned(),
+            ));
+            lines.push(field("Description", or_dash(&info.description)));
+            lines.push(field("Owner email", or_dash(&info.owner_email)));
+        }
+        lines.push(field(
+            "Global namespace",
+            format!("{}", response.is_global_namespace),
+        ));
+
+        if let Some(config) = response.config.as_ref() {
+            lines.push(text::Line::default());
+            lines.push(field(
+                "Retention period",
+                humanize_optional_duration(config.workflow_execution_retention_ttl),
+            ));
+            lines.push(field(
+                "History archival",
+                archival_state_as_str(config.history_archival_state).to_owned(),
+            ));
+            lines.push(field(
+                "History archival URI",
+                or_dash(&config.history_archival_uri),
+            ));
+            lines.push(field(
+                "Visibility archival",
+                archival_state_as_str(config.visibility_archival_state).to_owned(),
+            ));
+            lines.push(field(
+                "Visibility archival URI",
+                or_dash(&config.visibility_archival_uri),
+            ));
+        }
+
+        if let Some(info) = response.namespace_info.as_ref() {
+            if !info.data.is_empty() {
+                lines.push(text::Line::default());
+                lines.push(text::Line::from("Data:").fg(self.theme.header_foreground));
+                let mut data: Vec<_> = info.data.iter().collect();
+                data.sort();
+                for (key, value) in data {
+                    lines.push(text::Line::from(format!("  {}: {}", key, value)));
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+impl Keybindable for NamespaceWidget {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        match key {
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
+            key if self.settings.keybinds.matches(Action::Back, &key) => {
+                return Some(Navigation::Back(ViewWidget::WorkflowTable(
+                    WorkflowTableWidget::new(&self.temporal_client, &self.settings, self.theme),
+                )));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn keybinds(&self) -> &[(&str, &[&str])] {
+        &[("Previous view", &["Esc"]), ("Reload", &["Ctrl+r"])]
+    }
+}
+
+impl widgets::Widget for &NamespaceWidget {
+    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title(
+                text::Line::from(format!("Namespace {}", self.temporal_client.namespace()))
+                    .fg(self.theme.foreground),
+            )
+            .border_style(style::Style::new().fg(self.theme.border));
+
+        let lines = match (
+            self.namespace.read().unwrap().as_ref(),
+            &*self.loading_state.read().unwrap(),
+        ) {
+            (_, LoadingState::Error(e)) => vec![text::Line::from(e.clone())],
+            (Some(response), _) => self.lines(response),
+            (None, _) => vec![text::Line::from("Loading namespace...")],
+        };
+
+        widgets::Paragraph::new(lines)
+            .fg(self.theme.foreground)
+            .wrap(widgets::Wrap { trim: false })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,304 @@
+//! Client-side validation of Visibility queries, to point out common syntax errors without
+//! waiting for the server to reject them.
+
+/// Search attributes every namespace has. Queries may also use custom search attributes, so an
+/// unknown field alone doesn't make a query invalid.
+const KNOWN_FIELDS: [&str; 21] = [
+    "WorkflowId",
+    "WorkflowType",
+    "RunId",
+    "ExecutionStatus",
+    "StartTime",
+    "CloseTime",
+    "ExecutionTime",
+    "ExecutionDuration",
+    "TaskQueue",
+    "HistoryLength",
+    "HistorySizeBytes",
+    "StateTransitionCount",
+    "BuildIds",
+    "BinaryChecksums",
+    "BatcherUser",
+    "TemporalChangeVersion",
+    "ParentWorkflowId",
+    "ParentRunId",
+    "RootWorkflowId",
+    "RootRunId",
+    "TemporalScheduledById",
+];
+
+const KEYWORDS: [&str; 12] = [
+    "AND",
+    "OR",
+    "NOT",
+    "IN",
+    "BETWEEN",
+    "IS",
+    "NULL",
+    "STARTS_WITH",
+    "ORDER",
+    "BY",
+    "ASC",
+    "DESC",
+];
+
+const OPERATORS: [&str; 7] = ["=", "!=", "<>", "<", "<=", ">", ">="];
+
+/// Outcome of validating a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validation {
+    Valid,
+    /// The query is malformed, with the problem found at byte `position`.
+    Invalid {
+        message: String,
+        position: usize,
+    },
+    /// The query may be wrong, but only the server can tell.
+    Inconclusive {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'q> {
+    Word(&'q str),
+    Literal,
+    Operator(&'q str),
+    Open,
+    Close,
+    Comma,
+}
+
+impl Token<'_> {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn is_any_keyword(&self) -> bool {
+        KEYWORDS.iter().any(|keyword| self.is_keyword(keyword))
+    }
+
+    /// A value or a field, as opposed to keywords and punctuation.
+    fn is_operand(&self) -> bool {
+        match self {
+            Token::Literal => true,
+            Token::Word(_) => !self.is_any_keyword(),
+            _ => false,
+        }
+    }
+}
+
+fn invalid(message: String, position: usize) -> Validation {
+    Validation::Invalid { message, position }
+}
+
+fn tokenize(query: &str) -> Result<Vec<(usize, Token<'_>)>, Validation> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push((start, Token::Open)),
+            ')' => tokens.push((start, Token::Close)),
+            ',' => tokens.push((start, Token::Comma)),
+            '\'' | '"' => {
+                let mut escaped = false;
+                let mut terminated = false;
+                for (_, next) in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        terminated = true;
+                        break;
+                    }
+                }
+                if !terminated {
+                    return Err(invalid(format!("unterminated {} quote", c), start));
+                }
+                tokens.push((start, Token::Literal));
+            }
+            '=' | '!' | '<' | '>' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) =
+                    chars.next_if(|(_, n)| matches!(n, '=' | '!' | '<' | '>'))
+                {
+                    end = i + next.len_utf8();
+                }
+                let operator = &query[start..end];
+                if !OPERATORS.contains(&operator) {
+                    return Err(invalid(format!("unknown operator {}", operator), start));
+                }
+                tokens.push((start, Token::Operator(operator)));
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) = chars.next_if(|(_, n)| {
+                    !n.is_whitespace()
+                        && !matches!(n, '(' | ')' | ',' | '\'' | '"' | '=' | '!' | '<' | '>')
+                }) {
+                    end = i + next.len_utf8();
+                }
+                tokens.push((start, Token::Word(&query[start..end])));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Validate the syntax of a Visibility `query`.
+pub fn validate(query: &str) -> Validation {
+    let tokens = match tokenize(query) {
+        Ok(tokens) => tokens,
+        Err(validation) => return validation,
+    };
+
+    let mut open_parens = Vec::new();
+    let mut unknown_fields = Vec::new();
+
+    for (i, (position, token)) in tokens.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|p| tokens[p].1);
+        let next = tokens.get(i + 1).map(|(_, t)| *t);
+
+        match token {
+            Token::Open => open_parens.push(*position),
+            Token::Close if open_parens.pop().is_none() => {
+                return invalid("unmatched closing parenthesis".to_string(), *position);
+            }
+            Token::Operator(operator) => {
+                match previous {
+                    Some(Token::Word(field)) if !previous.unwrap().is_any_keyword() => {
+                        unknown_fields.push(field)
+                    }
+                    _ => {
+                        return invalid(
+                            format!("expected a search attribute before {}", operator),
+                            *position,
+                        )
+                    }
+                }
+                if !next.is_some_and(|t| t.is_operand()) {
+                    return invalid(format!("expected a value after {}", operator), *position);
+                }
+            }
+            Token::Word(word) if token.is_keyword("AND") || token.is_keyword("OR") => {
+                let follows_condition = matches!(previous, Some(Token::Close))
+                    || previous.is_some_and(|t| t.is_operand() || t.is_keyword("NULL"));
+                let precedes_condition = matches!(next, Some(Token::Open))
+                    || next.is_some_and(|t| t.is_operand() || t.is_keyword("NOT"));
+                if !follows_condition || !precedes_condition {
+                    return invalid(
+                        format!(
+                            "expected a condition on both sides of {}",
+                            word.to_uppercase()
+                        ),
+                        *position,
+                    );
+                }
+            }
+            Token::Word(field)
+                if !token.is_any_keyword()
+                    && next.is_some_and(|t| {
+                        t.is_keyword("IN")
+                            || t.is_keyword("BETWEEN")
+                            || t.is_keyword("STARTS_WITH")
+                            || t.is_keyword("IS")
+                            || t.is_keyword("NOT")
+                    }) =>
+            {
+                unknown_fields.push(field)
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(position) = open_parens.pop() {
+        return invalid("unclosed parenthesis".to_string(), position);
+    }
+
+    unknown_fields.retain(|field| !KNOWN_FIELDS.contains(&field.trim_matches('`')));
+    match unknown_fields.first() {
+        Some(field) => Validation::Inconclusive {
+            message: format!("{} is not a system search attribute", field),
+        },
+        None => Validation::Valid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid_at(query: &str) -> usize {
+        match validate(query) {
+            Validation::Invalid { position, .. } => position,
+            validation => panic!("expected {:?} to be invalid, got {:?}", query, validation),
+        }
+    }
+
+    #[test]
+    fn accepts_quoted_values_with_escapes_and_operators() {
+        assert_eq!(
+            validate(r#"WorkflowType = 'Order\'s "flow"' AND HistoryLength >= 10"#),
+            Validation::Valid
+        );
+        assert_eq!(
+            validate("(ExecutionStatus != 'Running' OR TaskQueue <> \"q\") AND NOT RunId = 'x'"),
+            Validation::Valid
+        );
+        assert_eq!(
+            validate("WorkflowId STARTS_WITH 'order-' ORDER BY StartTime DESC"),
+            Validation::Valid
+        );
+    }
+
+    #[test]
+    fn reports_where_quotes_and_operators_go_wrong() {
+        assert_eq!(
+            validate("WorkflowId = 'unterminated"),
+            invalid("unterminated ' quote".to_string(), 13)
+        );
+        assert_eq!(
+            validate("HistoryLength => 3"),
+            invalid("unknown operator =>".to_string(), 14)
+        );
+        assert_eq!(
+            validate("= 'x'"),
+            invalid("expected a search attribute before =".to_string(), 0)
+        );
+        assert_eq!(
+            validate("WorkflowId ="),
+            invalid("expected a value after =".to_string(), 11)
+        );
+    }
+
+    #[test]
+    fn reports_where_conditions_and_parentheses_go_wrong() {
+        assert_eq!(invalid_at("WorkflowId = 'a' AND"), 17);
+        assert_eq!(invalid_at("or WorkflowId = 'a'"), 0);
+        assert_eq!(invalid_at("(WorkflowId = 'a'"), 0);
+        assert_eq!(invalid_at("WorkflowId = 'a')"), 16);
+    }
+
+    #[test]
+    fn positions_are_byte_offsets_past_multibyte_text() {
+        assert_eq!(
+            invalid_at("WorkflowId = 'ñ' AND"),
+            "WorkflowId = 'ñ' ".len()
+        );
+    }
+
+    #[test]
+    fn leaves_custom_search_attributes_to_the_server() {
+        assert_eq!(
+            validate("CustomerId = 'c' AND WorkflowId = 'w'"),
+            Validation::Inconclusive {
+                message: "CustomerId is not a system search attribute".to_string()
+            }
+        );
+        assert_eq!(validate("`WorkflowType` IN ('a', 'b')"), Validation::Valid);
+    }
+}
@@ -0,0 +1,278 @@
+use std::sync;
+
+use crossterm::event;
+use ratatui::{buffer, layout, style, style::Stylize, text, widgets};
+use temporal_client::{self, tonic, WorkflowClientTrait, WorkflowService};
+use temporal_sdk_core_protos::temporal::api::{
+    enums::v1 as enums, taskqueue::v1 as taskqueue, workflowservice::v1 as service,
+};
+use tokio::sync::mpsc;
+
+use crate::keymap::Action;
+use crate::settings::Settings;
+use crate::theme::Theme;
+use crate::widgets::common::{BackgroundTasks, LoadingState, Message, TimeFormat};
+use crate::widgets::workflow::WorkflowWidget;
+use crate::widgets::workflow_table::WorkflowTableWidget;
+use crate::widgets::{Keybindable, Navigation, ViewWidget};
+
+/// The pollers of a task queue, each along with the type of tasks it polls for.
+type Pollers = Vec<(enums::TaskQueueType, taskqueue::PollerInfo)>;
+
+/// The workers polling a task queue, to tell whether any are running and keeping up.
+#[derive(Debug, Clone)]
+pub struct TaskQueueWidget {
+    temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+    sender: sync::Arc<Option<mpsc::Sender<Message>>>,
+    tasks: BackgroundTasks,
+    theme: Theme,
+    settings: sync::Arc<Settings>,
+    /// The name of the task queue to describe.
+    task_queue: String,
+    /// The workflow we came from, returned to when going back, by its ID and run ID.
+    workflow: Option<(String, Option<String>)>,
+    /// Pollers of workflow tasks and of activity tasks, in that order.
+    pollers: sync::Arc<sync::RwLock<Option<Pollers>>>,
+    loading_state: sync::Arc<sync::RwLock<LoadingState>>,
+}
+
+impl TaskQueueWidget {
+    pub fn new(
+        temporal_client: &sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+        settings: &sync::Arc<Settings>,
+        task_queue: &str,
+        theme: Theme,
+    ) -> Self {
+        Self {
+            temporal_client: temporal_client.clone(),
+            sender: sync::Arc::new(None),
+            tasks: BackgroundTasks::default(),
+            theme,
+            settings: settings.clone(),
+            task_queue: task_queue.to_owned(),
+            workflow: None,
+            pollers: sync::Arc::new(sync::RwLock::new(None)),
+            loading_state: sync::Arc::new(sync::RwLock::new(LoadingState::Idle)),
+        }
+    }
+
+    /// Go back to the workflow with `workflow_id` and `run_id` instead of the workflow table.
+    pub fn with_workflow(mut self, workflow_id: &str, run_id: Option<&str>) -> Self {
+        self.workflow = Some((workflow_id.to_owned(), run_id.map(str::to_owned)));
+        self
+    }
+
+    /// A short name for the tab showing this task queue.
+    pub fn tab_title(&self) -> String {
+        format!("Task queue {}", self.task_queue)
+    }
+
+    pub fn run(&mut self) {
+        let (tx, rx) = mpsc::channel(32);
+        *sync::Arc::get_mut(&mut self.sender).unwrap() = Some(tx);
+
+        let this = self.clone(); // clone the widget to pass to the background task
+        self.tasks.spawn(this.fetch_pollers(rx));
+    }
+
+    async fn fetch_pollers(mut self, mut receiver: mpsc::Receiver<Message>) {
+        log::debug!(widget = "TaskQueueWidget"; "Starting fetch_pollers loop");
+        while let Some(message) = receiver.recv().await {
+            if let Message::Reload = message {
+                log::debug!(widget = "TaskQueueWidget"; "Reloading");
+                self.set_loading_state(LoadingState::Loading);
+
+                let mut pollers = Vec::new();
+                for task_queue_type in [
+                    enums::TaskQueueType::Workflow,
+                    enums::TaskQueueType::Activity,
+                ] {
+                    match self.describe_task_queue(task_queue_type).await {
+                        Ok(response) => pollers.extend(
+                            response
+                                .pollers
+                                .into_iter()
+                                .map(|poller| (task_queue_type, poller)),
+                        ),
+                        Err(e) => {
+                            self.set_loading_state(LoadingState::Error(format!(
+                                "describe task queue request failed: {}",
+                                e
+                            )));
+                            break;
+                        }
+                    }
+                }
+
+                if !matches!(*self.loading_state.read().unwrap(), LoadingState::Error(_)) {
+                    *self.pollers.write().unwrap() = Some(pollers);
+                    self.set_loading_state(LoadingState::Reloaded);
+                }
+            }
+        }
+    }
+
+    /// Describe the task queue for tasks of `task_queue_type`.
+    async fn describe_task_queue(
+        &self,
+        task_queue_type: enums::TaskQueueType,
+    ) -> Result<service::DescribeTaskQueueResponse, tonic::Status> {
+        let mut temporal_client = (*self.temporal_client).clone();
+        let request = service::DescribeTaskQueueRequest {
+            namespace: self.temporal_client.namespace().to_string(),
+            task_queue: Some(taskqueue::TaskQueue {
+                name: self.task_queue.clone(),
+                kind: enums::TaskQueueKind::Normal as i32,
+                ..Default::default()
+            }),
+            task_queue_type: task_queue_type as i32,
+            ..Default::default()
+        };
+        temporal_client
+            .describe_task_queue(tonic::Request::new(request))
+            .await
+            .map(tonic::Response::into_inner)
+    }
+
+    fn set_loading_state(&mut self, loading_state: LoadingState) {
+        *self.loading_state.write().unwrap() = loading_state;
+    }
+
+    pub async fn reload(&self) {
+        let sender = self.sender.as_ref().clone();
+        sender.unwrap().send(Message::Reload).await.unwrap();
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Stop loading in the background, once this widget is no longer displayed.
+    pub fn shutdown(&self) {
+        self.tasks.shutdown();
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading_state.read().unwrap().is_busy()
+    }
+
+    pub fn busy_message(&self) -> Option<String> {
+        self.loading_state.read().unwrap().busy_message()
+    }
+}
+
+impl Keybindable for TaskQueueWidget {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        match key {
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
+            key if self.settings.keybinds.matches(Action::Back, &key) => {
+                return Some(Navigation::Back(match self.workflow.as_ref() {
+                    Some((workflow_id, run_id)) => ViewWidget::Workflow(WorkflowWidget::new(
+                        &self.temporal_client,
+                        &self.settings,
+                        workflow_id,
+                        run_id.as_deref(),
+                        self.theme,
+                    )),
+                    None => ViewWidget::WorkflowTable(WorkflowTableWidget::new(
+                        &self.temporal_client,
+                        &self.settings,
+                        self.theme,
+                    )),
+                }));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn keybinds(&self) -> &[(&str, &[&str])] {
+        &[("Previous view", &["Esc"]), ("Reload", &["Ctrl+r"])]
+    }
+}
+
+impl widgets::Widget for &TaskQueueWidget {
+    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title(
+                text::Line::from(format!("Task queue {}", self.task_queue))
+                    .fg(self.theme.foreground),
+            )
+            .border_style(style::Style::new().fg(self.theme.border))
+            .bg(self.theme.background);
+
+        let message = match (
+            self.pollers.read().unwrap().as_ref(),
+            &*self.loading_state.read().unwrap(),
+        ) {
+            (_, LoadingState::Error(e)) => Some(e.clone()),
+            (Some(pollers), _) if pollers.is_empty() => {
+                Some("No workers are polling this task queue".to_string())
+            }
+            (Some(_), _) => None,
+            (None, _) => Some("Loading pollers...".to_string()),
+        };
+        if let Some(message) = message {
+            widgets::Paragraph::new(message)
+                .fg(self.theme.foreground)
+                .block(block)
+                .render(area, buf);
+            return;
+        }
+
+        let pollers = self.pollers.read().unwrap();
+        let rows = pollers
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, (task_queue_type, poller))| {
+                let last_access = poller
+                    .last_access_time
+                    .as_ref()
+                    .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32))
+                    .map(|dt| TimeFormat::Relative.format(dt))
+                    .unwrap_or("-".to_string());
+                widgets::Row::new(vec![
+                    match task_queue_type {
+                        enums::TaskQueueType::Workflow => "Workflow",
+                        enums::TaskQueueType::Activity => "Activity",
+                        _ => "Other",
+                    }
+                    .to_string(),
+                    poller.identity.clone(),
+                    last_access,
+                    format!("{:.2}", poller.rate_per_second),
+                ])
+                .bg(if i % 2 == 0 {
+                    self.theme.background
+                } else {
+                    self.theme.alt_background
+                })
+            });
+
+        let header = ["Type", "Identity", "Last access", "Rate per second"]
+            .into_iter()
+            .map(widgets::Cell::from)
+            .collect::<widgets::Row>()
+            .style(
+                style::Style::new()
+                    .fg(self.theme.header_foreground)
+                    .bg(self.theme.header_background),
+            );
+
+        widgets::Table::new(
+            rows,
+            [
+                layout::Constraint::Length(10),
+                layout::Constraint::Fill(1),
+                layout::Constraint::Length(16),
+                layout::Constraint::Length(16),
+            ],
+        )
+        .header(header)
+        .fg(self.theme.foreground)
+        .block(block)
+        .render(area, buf);
+    }
+}
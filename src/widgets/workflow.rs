@@ -1,3 +1,4 @@
+use std::borrow;
 use std::str;
 
 use crossterm::event;
@@ -5,21 +6,96 @@ use ratatui::{
     buffer, layout, prelude::StatefulWidget, prelude::Widget, style, style::Stylize, text, widgets,
 };
 use std::collections;
+use std::ops;
 use std::sync;
-use temporal_client::WorkflowClientTrait;
+use temporal_client::{tonic, WorkflowClientTrait};
 use temporal_sdk_core_protos::temporal::api::{
     common::v1 as temporal_common, enums::v1 as enums, failure::v1 as failure,
-    history::v1 as history, sdk::v1 as sdk, workflow::v1 as workflow,
+    history::v1 as history, query::v1 as query, sdk::v1 as sdk, workflow::v1 as workflow,
     workflowservice::v1 as service,
 };
 use tokio::sync::mpsc;
 use tokio::task;
 use tokio::time;
 
+use crate::audit;
+use crate::clipboard;
+use crate::codec;
+use crate::keymap::Action;
+use crate::settings::{Settings, DEFAULT_MAX_PAYLOAD_RENDER_BYTES};
 use crate::theme::Theme;
-use crate::widgets::common::{LoadingState, Message, WorkflowExecution};
-use crate::widgets::workflow_table::WorkflowTableWidget;
-use crate::widgets::{Keybindable, ViewWidget};
+use crate::widgets::common::{
+    render_error_banner, to_pretty_json, truncate_middle, BackgroundTasks, LoadingState, Message,
+    PendingJump, ReloadQueue, TimeFormat, WorkflowExecution, COPIED_NOTICE_DURATION,
+};
+use crate::widgets::task_queue::TaskQueueWidget;
+use crate::widgets::workflow_table::{QueryInput, WorkflowTableWidget};
+use crate::widgets::{Keybindable, Navigation, ViewWidget};
+
+mod renderers;
+
+type Keybinds = &'static [(&'static str, &'static [&'static str])];
+
+/// Keybinds to move through the history, wherever it's browsed.
+const HISTORY_NAVIGATION_KEYBINDS: Keybinds = &[
+    ("Up", &["k", "↑"]),
+    ("Down", &["j", "↓"]),
+    ("Page down/up", &["PgDn", "PgUp"]),
+    ("First/last event", &["gg", "G"]),
+    ("Search", &["/"]),
+    ("Expand event", &["Enter"]),
+];
+
+/// Keybinds acting on the workflow and its history, with or without an event's detail beside it.
+const HISTORY_KEYBINDS: Keybinds = &[
+    ("Next failure", &["f"]),
+    ("Toggle tree", &["t"]),
+    ("Relative times", &["T"]),
+    ("Copy IDs", &["y"]),
+    ("Mark/fold", &["Space"]),
+    ("Export marked", &["X"]),
+    ("Copy marked", &["Y"]),
+    ("Describe", &["d"]),
+    ("Describe response", &["D"]),
+    ("Reset points", &["p"]),
+    ("Pending activities", &["a"]),
+    ("Memo and search attributes", &["M"]),
+    ("Toggle header", &["h"]),
+    ("Export report", &["m"]),
+    ("Watch heartbeats", &["w"]),
+    ("Task queue workers", &["W"]),
+    ("Children", &["C"]),
+    ("Stack trace", &["Q"]),
+    ("Current run", &["O"]),
+    ("My events", &["i"]),
+    ("Same identity", &["I"]),
+    ("Hide noise", &["H"]),
+    ("Event category", &["e"]),
+];
+
+/// Keybinds of the event's detail displayed beside the history.
+const SPLIT_DETAIL_KEYBINDS: Keybinds =
+    &[("Resize split", &["<", ">"]), ("Export payloads", &["x"])];
+
+const VIEW_KEYBINDS: Keybinds = &[
+    ("Toggle split", &["s"]),
+    ("Previous view", &["Esc"]),
+    ("Reload", &["Ctrl+r"]),
+];
+
+static BROWSING_KEYBINDS: sync::LazyLock<Vec<(&str, &[&str])>> =
+    sync::LazyLock::new(|| [HISTORY_NAVIGATION_KEYBINDS, HISTORY_KEYBINDS, VIEW_KEYBINDS].concat());
+
+static BROWSING_SPLIT_DETAIL_KEYBINDS: sync::LazyLock<Vec<(&str, &[&str])>> =
+    sync::LazyLock::new(|| {
+        [
+            HISTORY_NAVIGATION_KEYBINDS,
+            HISTORY_KEYBINDS,
+            SPLIT_DETAIL_KEYBINDS,
+            VIEW_KEYBINDS,
+        ]
+        .concat()
+    });
 
 #[derive(Debug, Clone)]
 pub enum PendingActivityState {
@@ -54,57 +130,193 @@ impl TryFrom<i32> for PendingActivityState {
     }
 }
 
+/// Format a size in bytes with a single decimal in the largest fitting unit, like "4.2 KB".
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// The value found by following `path` of object keys and array indices into `value`.
+fn json_value_at<'v>(
+    value: &'v serde_json::Value,
+    path: &[String],
+) -> Option<&'v serde_json::Value> {
+    path.iter().try_fold(value, |value, segment| match value {
+        serde_json::Value::Object(object) => object.get(segment),
+        serde_json::Value::Array(array) => array.get(segment.parse::<usize>().ok()?),
+        _ => None,
+    })
+}
+
+/// The keys of an object, or the indices of an array, that can be followed into `value`.
+fn json_children(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::Object(object) => object.keys().cloned().collect(),
+        serde_json::Value::Array(array) => (0..array.len()).map(|i| i.to_string()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A payload to render, borrowed from the event carrying it so large payloads aren't copied on
+/// every frame.
 #[derive(Debug, Clone)]
-pub struct PayloadWidget {
-    metadata: collections::HashMap<String, Vec<u8>>,
-    data: Vec<u8>,
+pub struct PayloadWidget<'a> {
+    metadata: borrow::Cow<'a, collections::HashMap<String, Vec<u8>>>,
+    data: borrow::Cow<'a, [u8]>,
     title: String,
     theme: Theme,
+    /// Render at most this many bytes of data, the full payload can still be exported.
+    max_render_bytes: Option<usize>,
 }
 
-impl PayloadWidget {
+impl<'a> PayloadWidget<'a> {
     fn new(payload: temporal_common::Payload, title: &str, theme: Theme) -> Self {
         Self {
-            metadata: collections::HashMap::from_iter(payload.metadata),
-            data: payload.data,
+            metadata: borrow::Cow::Owned(payload.metadata),
+            data: borrow::Cow::Owned(payload.data),
             title: title.to_string(),
             theme,
+            max_render_bytes: None,
         }
     }
 
-    fn cloned(payload: &temporal_common::Payload, title: &str, theme: Theme) -> Self {
+    fn borrowed(payload: &'a temporal_common::Payload, title: &str, theme: Theme) -> Self {
         Self {
-            metadata: collections::HashMap::from_iter(payload.metadata.clone()),
-            data: payload.data.clone(),
+            metadata: borrow::Cow::Borrowed(&payload.metadata),
+            data: borrow::Cow::Borrowed(&payload.data),
             title: title.to_string(),
             theme,
+            max_render_bytes: None,
         }
     }
 
-    fn to_string_pretty(&self) -> String {
-        let data = str::from_utf8(&self.data).unwrap();
-        let metadata: collections::HashMap<&str, &str> = collections::HashMap::from_iter(
+    fn truncated(mut self, max_render_bytes: usize) -> Self {
+        self.max_render_bytes = Some(max_render_bytes);
+        self
+    }
+
+    fn is_truncated(&self) -> bool {
+        match self.max_render_bytes {
+            Some(max) => self.data.len() > max,
+            None => false,
+        }
+    }
+
+    /// Whether the data looks cut short, as servers may truncate large payloads in some
+    /// responses. Only the ending of JSON data is checked, to avoid parsing large payloads.
+    fn is_truncated_by_server(&self) -> bool {
+        if self.metadata.contains_key("truncated") {
+            return true;
+        }
+
+        match self.metadata.get("encoding").map(|e| e.as_slice()) {
+            Some(b"json/plain") => !matches!(
+                self.data.trim_ascii_end().last(),
+                None | Some(b'}' | b']' | b'"' | b'0'..=b'9' | b'e' | b'l')
+            ),
+            _ => false,
+        }
+    }
+
+    fn title_with_size(&self) -> String {
+        let title = format!("{} ({})", self.title, format_size(self.data.len()));
+        if self.is_truncated_by_server() {
+            format!("{} [truncated by server]", title)
+        } else {
+            title
+        }
+    }
+
+    /// Whether the data is binary, like protobuf messages, and not worth rendering as text.
+    fn is_binary(&self) -> bool {
+        matches!(
+            self.metadata.get("encoding").map(|e| e.as_slice()),
+            Some(b"binary/protobuf" | b"binary/null")
+        )
+    }
+
+    /// `data`, some or all of ours, as text. Binary data is replaced by a placeholder, and
+    /// anything else that isn't valid UTF-8 is decoded lossily.
+    fn data_to_string(&self, data: &[u8]) -> String {
+        if self.is_binary() {
+            format!("<binary {} bytes>", self.data.len())
+        } else {
+            String::from_utf8_lossy(data).into_owned()
+        }
+    }
+
+    /// The decoded data without the metadata wrapper: parsed if it's JSON, otherwise as text.
+    fn value(&self) -> serde_json::Value {
+        let data = || serde_json::Value::String(self.data_to_string(&self.data));
+        match self.metadata.get("encoding").map(|e| e.as_slice()) {
+            Some(b"json/plain") => serde_json::from_slice(&self.data).unwrap_or_else(|_| data()),
+            _ => data(),
+        }
+    }
+
+    fn dump(&self, data: &str) -> serde_json::Value {
+        let metadata: collections::HashMap<&str, String> = collections::HashMap::from_iter(
             self.metadata
                 .iter()
-                .map(|(key, val)| (key.as_str(), str::from_utf8(&val).unwrap())),
+                .map(|(key, val)| (key.as_str(), String::from_utf8_lossy(val).into_owned())),
         );
-        let dumped = serde_json::json!({
+        serde_json::json!({
             "metadata": metadata,
             "data": data,
-        });
+        })
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        self.dump(&self.data_to_string(&self.data))
+    }
+
+    fn to_string_pretty(&self) -> String {
+        serde_json::to_string_pretty(&self.to_json()).unwrap()
+    }
 
-        serde_json::to_string_pretty(&dumped).unwrap()
+    /// Like `to_string_pretty`, but only materializes up to `max_render_bytes` of data.
+    fn to_string_pretty_truncated(&self) -> String {
+        match self.max_render_bytes {
+            Some(max) if self.is_truncated() => {
+                // The cut may land in the middle of a character, which is decoded lossily.
+                let data = self.data_to_string(&self.data[..max]);
+                serde_json::to_string_pretty(&self.dump(&data)).unwrap()
+            }
+            _ => self.to_string_pretty(),
+        }
     }
 }
 
-impl widgets::Widget for &PayloadWidget {
+impl widgets::Widget for &PayloadWidget<'_> {
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
         let payload_block = widgets::Block::bordered()
             .border_type(widgets::BorderType::Rounded)
             .border_style(style::Style::new().fg(self.theme.border))
-            .title(self.title.as_str().fg(self.theme.foreground));
+            .title(self.title_with_size().fg(self.theme.foreground));
+        let payload_block = if self.is_truncated() {
+            payload_block.title_bottom(
+                format!(
+                    "Payload truncated ({}), press x to export",
+                    format_size(self.data.len())
+                )
+                .fg(self.theme.header_foreground),
+            )
+        } else {
+            payload_block
+        };
 
-        widgets::Paragraph::new(self.to_string_pretty().fg(self.theme.foreground))
+        widgets::Paragraph::new(self.to_string_pretty_truncated().fg(self.theme.foreground))
             .block(payload_block)
             .wrap(widgets::Wrap { trim: false })
             .render(area, buf);
@@ -116,16 +328,33 @@ pub struct FailureWidget {
     message: String,
     source: String,
     stack_trace: String,
+    title: String,
 }
 
 impl FailureWidget {
-    fn to_string_pretty(&self) -> String {
-        let dumped = serde_json::json!({
-            "message": self.message,
-            "stackTrace": self.stack_trace,
-        });
+    fn titled(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
 
-        serde_json::to_string_pretty(&dumped).unwrap()
+    /// The message and source of the failure, each on its own line.
+    fn summary(&self) -> Vec<text::Line<'_>> {
+        let mut lines: Vec<text::Line> = self
+            .message
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match i {
+                0 => text::Line::from(vec!["Message: ".bold(), line.into()]),
+                _ => text::Line::from(line),
+            })
+            .collect();
+        if !self.source.is_empty() {
+            lines.push(text::Line::from(vec![
+                "Source: ".bold(),
+                self.source.as_str().into(),
+            ]));
+        }
+        lines
     }
 }
 
@@ -135,6 +364,7 @@ impl From<failure::Failure> for FailureWidget {
             message: f.message,
             source: f.source,
             stack_trace: f.stack_trace,
+            title: "Failure".to_string(),
         }
     }
 }
@@ -145,6 +375,7 @@ impl From<&failure::Failure> for FailureWidget {
             message: f.message.clone(),
             source: f.source.clone(),
             stack_trace: f.stack_trace.clone(),
+            title: "Failure".to_string(),
         }
     }
 }
@@ -153,12 +384,42 @@ impl widgets::Widget for &FailureWidget {
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
         let failure_block = widgets::Block::bordered()
             .border_type(widgets::BorderType::Rounded)
-            .title("Failure");
+            .title(self.title.as_str());
+        let inner_area = failure_block.inner(area);
+        failure_block.render(area, buf);
+
+        let summary = self.summary();
+        // How many lines the summary takes once wrapped, leaving room for the stack trace
+        let width = inner_area.width.max(1) as usize;
+        let summary_height: usize = summary
+            .iter()
+            .map(|line| line.width().div_ceil(width).max(1))
+            .sum();
+        let summary_height = match self.stack_trace.is_empty() {
+            true => inner_area.height,
+            false => (summary_height as u16).min(inner_area.height / 2),
+        };
 
-        widgets::Paragraph::new(self.to_string_pretty())
-            .block(failure_block)
+        let [summary_area, stack_trace_area] = layout::Layout::vertical([
+            layout::Constraint::Length(summary_height),
+            layout::Constraint::Fill(1),
+        ])
+        .areas(inner_area);
+
+        widgets::Paragraph::new(summary)
             .wrap(widgets::Wrap { trim: false })
-            .render(area, buf);
+            .render(summary_area, buf);
+
+        if !self.stack_trace.is_empty() {
+            widgets::Paragraph::new(self.stack_trace.as_str())
+                .block(
+                    widgets::Block::bordered()
+                        .border_type(widgets::BorderType::Rounded)
+                        .title("Stack trace"),
+                )
+                .wrap(widgets::Wrap { trim: false })
+                .render(stack_trace_area, buf);
+        }
     }
 }
 
@@ -167,7 +428,7 @@ pub struct PendingActivity {
     id: String,
     r#type: Option<String>,
     state: enums::PendingActivityState,
-    heartbeat_details: Option<Vec<PayloadWidget>>,
+    heartbeat_details: Option<Vec<PayloadWidget<'static>>>,
     last_heartbeat_time: Option<chrono::DateTime<chrono::Utc>>,
     last_started_time: Option<chrono::DateTime<chrono::Utc>>,
     attempt: u32,
@@ -184,16 +445,8 @@ pub struct PendingActivity {
 impl PendingActivity {
     fn new(info: workflow::PendingActivityInfo, theme: Theme) -> Result<Self, anyhow::Error> {
         let state = enums::PendingActivityState::try_from(info.state)?;
-        let last_failure = if let Some(f) = info.last_failure {
-            Some(FailureWidget {
-                message: f.message,
-                source: f.source,
-                stack_trace: f.stack_trace,
-            })
-        } else {
-            None
-        };
-        let heartbeat_details: Option<Vec<PayloadWidget>> =
+        let last_failure = info.last_failure.map(FailureWidget::from);
+        let heartbeat_details: Option<Vec<PayloadWidget<'static>>> =
             if let Some(payloads) = info.heartbeat_details {
                 Some(
                     payloads
@@ -215,7 +468,7 @@ impl PendingActivity {
                 .last_heartbeat_time
                 .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
             last_started_time: info
-                .last_heartbeat_time
+                .last_started_time
                 .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
             attempt: info.attempt as u32,
             maximum_attempts: info.maximum_attempts as u32,
@@ -238,12 +491,148 @@ impl PendingActivity {
     }
 }
 
+impl PendingActivity {
+    fn state_as_str(&self) -> &'static str {
+        match self.state {
+            enums::PendingActivityState::Scheduled => "Scheduled",
+            enums::PendingActivityState::Started => "Started",
+            enums::PendingActivityState::CancelRequested => "Cancel requested",
+            state => state
+                .as_str_name()
+                .trim_start_matches("PENDING_ACTIVITY_STATE_"),
+        }
+    }
+
+    fn is_heartbeating(&self) -> bool {
+        self.last_heartbeat_time.is_some() || self.heartbeat_details.is_some()
+    }
+
+    /// Replace heartbeat fields with those of a newer describe of the same activity.
+    fn update_heartbeat(&mut self, newer: PendingActivity) {
+        self.last_heartbeat_time = newer.last_heartbeat_time;
+        self.heartbeat_details = newer.heartbeat_details;
+    }
+}
+
+/// A Nexus operation the workflow is waiting on.
+#[derive(Debug, Clone)]
+pub struct PendingNexusOperation {
+    endpoint: String,
+    service: String,
+    operation: String,
+    state: enums::PendingNexusOperationState,
+    attempt: u32,
+    scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    next_attempt_schedule_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Why the operation is blocked, or the last attempt's failure message otherwise.
+    problem: Option<String>,
+}
+
+impl TryFrom<workflow::PendingNexusOperationInfo> for PendingNexusOperation {
+    type Error = anyhow::Error;
+
+    fn try_from(info: workflow::PendingNexusOperationInfo) -> Result<Self, Self::Error> {
+        let state = enums::PendingNexusOperationState::try_from(info.state)?;
+        let problem = if !info.blocked_reason.is_empty() {
+            Some(info.blocked_reason)
+        } else {
+            info.last_attempt_failure.map(|failure| failure.message)
+        };
+
+        Ok(Self {
+            endpoint: info.endpoint,
+            service: info.service,
+            operation: info.operation,
+            state,
+            attempt: info.attempt as u32,
+            scheduled_time: info
+                .scheduled_time
+                .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
+            next_attempt_schedule_time: info
+                .next_attempt_schedule_time
+                .and_then(|t| chrono::DateTime::from_timestamp(t.seconds, t.nanos as u32)),
+            problem,
+        })
+    }
+}
+
+impl PendingNexusOperation {
+    fn state_as_str(&self) -> &'static str {
+        match self.state {
+            enums::PendingNexusOperationState::Unspecified => "Unspecified",
+            enums::PendingNexusOperationState::Scheduled => "Scheduled",
+            enums::PendingNexusOperationState::BackingOff => "Backing off",
+            enums::PendingNexusOperationState::Started => "Started",
+            enums::PendingNexusOperationState::Blocked => "Blocked",
+        }
+    }
+}
+
+impl widgets::Widget for &PendingActivity {
+    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title(
+                format!(
+                    "Heartbeats: {} ({})",
+                    self.r#type.as_deref().unwrap_or("-"),
+                    self.id
+                )
+                .fg(self.theme.header_foreground),
+            )
+            .border_style(style::Style::new().fg(self.theme.border));
+        let inner_area = block.inner(area);
+        widgets::Widget::render(block, area, buf);
+
+        let vertical =
+            &layout::Layout::vertical([layout::Constraint::Length(2), layout::Constraint::Fill(1)]);
+        let [lines_area, details_area] = vertical.areas(inner_area);
+
+        widgets::Paragraph::new(vec![
+            text::Line::from(vec![
+                "Last heartbeat: ".into(),
+                text::Span::from(
+                    self.last_heartbeat_time
+                        .map(|dt| format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")))
+                        .unwrap_or("-".to_string()),
+                ),
+            ]),
+            text::Line::from(vec![
+                "Attempt: ".into(),
+                text::Span::from(format!("{}/{}", self.attempt, self.maximum_attempts)),
+            ]),
+        ])
+        .fg(self.theme.foreground)
+        .render(lines_area, buf);
+
+        if let Some(details) = self
+            .heartbeat_details
+            .as_ref()
+            .and_then(|details| details.first())
+        {
+            details.render(details_area, buf);
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Attributes {
     inner: Option<history::history_event::Attributes>,
     table_state: widgets::TableState,
 }
 
+fn retry_state_as_str(retry_state: i32) -> &'static str {
+    enums::RetryState::try_from(retry_state)
+        .unwrap_or(enums::RetryState::Unspecified)
+        .as_str_name()
+}
+
+fn task_queue_kind_as_str(kind: i32) -> &'static str {
+    enums::TaskQueueKind::try_from(kind)
+        .unwrap_or(enums::TaskQueueKind::Unspecified)
+        .as_str_name()
+}
+
 #[derive(Debug, Clone)]
 pub struct EventWidget {
     id: i64,
@@ -251,9 +640,67 @@ pub struct EventWidget {
     r#type: enums::EventType,
     attributes: Option<history::history_event::Attributes>,
     theme: Theme,
+    max_payload_render_bytes: usize,
 }
 
 impl EventWidget {
+    fn payload<'a>(&self, payload: &'a temporal_common::Payload, title: &str) -> PayloadWidget<'a> {
+        PayloadWidget::borrowed(payload, title, self.theme).truncated(self.max_payload_render_bytes)
+    }
+
+    /// All the payloads carried by this event, with the title they are rendered under.
+    fn payloads(&self) -> Vec<PayloadWidget<'_>> {
+        use history::history_event::Attributes;
+
+        let (payloads, title) = match self.attributes.as_ref() {
+            Some(Attributes::WorkflowExecutionStartedEventAttributes(attrs)) => {
+                (attrs.input.as_ref(), "Input")
+            }
+            Some(Attributes::WorkflowExecutionCompletedEventAttributes(attrs)) => {
+                (attrs.result.as_ref(), "Result")
+            }
+            Some(Attributes::WorkflowExecutionSignaledEventAttributes(attrs)) => {
+                (attrs.input.as_ref(), "Input")
+            }
+            Some(Attributes::ActivityTaskScheduledEventAttributes(attrs)) => {
+                (attrs.input.as_ref(), "Input")
+            }
+            Some(Attributes::ActivityTaskCompletedEventAttributes(attrs)) => {
+                (attrs.result.as_ref(), "Result")
+            }
+            Some(Attributes::ActivityTaskCanceledEventAttributes(attrs)) => {
+                (attrs.details.as_ref(), "Details")
+            }
+            _ => (None, ""),
+        };
+
+        payloads
+            .map(|payloads| {
+                payloads
+                    .payloads
+                    .iter()
+                    .map(|p| self.payload(p, title))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// A summary of this event with its payloads, to export it.
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "event_id": self.id,
+            "event_time": self.time.map(|dt| dt.to_rfc3339()),
+            "event_type": self.type_as_string(),
+            "identity": self.identity(),
+            "failure": self.failure().map(|failure| failure.message.as_str()),
+            "payloads": self
+                .payloads()
+                .iter()
+                .map(|p| serde_json::json!({"title": p.title, "payload": p.to_json()}))
+                .collect::<Vec<_>>(),
+        })
+    }
+
     pub fn time_as_string(&self) -> String {
         match self.time {
             Some(dt) => format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")),
@@ -261,6 +708,225 @@ impl EventWidget {
         }
     }
 
+    /// The ID of the `WorkflowTaskCompleted` event whose commands produced this event, if any.
+    pub fn workflow_task_completed_event_id(&self) -> Option<i64> {
+        use history::history_event::Attributes;
+
+        match self.attributes.as_ref()? {
+            Attributes::WorkflowExecutionCompletedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::WorkflowExecutionFailedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::WorkflowExecutionCanceledEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::ActivityTaskScheduledEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::ActivityTaskCancelRequestedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::TimerStartedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::TimerCanceledEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::MarkerRecordedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::UpsertWorkflowSearchAttributesEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            Attributes::NexusOperationScheduledEventAttributes(attrs) => {
+                Some(attrs.workflow_task_completed_event_id)
+            }
+            _ => None,
+        }
+    }
+
+    /// Identity of the worker or client that caused this event, if recorded.
+    pub fn identity(&self) -> Option<&str> {
+        use history::history_event::Attributes;
+
+        let identity = match self.attributes.as_ref()? {
+            Attributes::WorkflowExecutionStartedEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowTaskStartedEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowTaskCompletedEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowTaskFailedEventAttributes(attrs) => &attrs.identity,
+            Attributes::ActivityTaskStartedEventAttributes(attrs) => &attrs.identity,
+            Attributes::ActivityTaskCompletedEventAttributes(attrs) => &attrs.identity,
+            Attributes::ActivityTaskFailedEventAttributes(attrs) => &attrs.identity,
+            Attributes::ActivityTaskCanceledEventAttributes(attrs) => &attrs.identity,
+            Attributes::TimerCanceledEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowExecutionCancelRequestedEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowExecutionSignaledEventAttributes(attrs) => &attrs.identity,
+            Attributes::WorkflowExecutionTerminatedEventAttributes(attrs) => &attrs.identity,
+            _ => return None,
+        };
+
+        if identity.is_empty() {
+            None
+        } else {
+            Some(identity)
+        }
+    }
+
+    /// ID of the ActivityTaskScheduled event of the activity this event belongs to, if any.
+    pub fn activity_scheduled_event_id(&self) -> Option<i64> {
+        use history::history_event::Attributes;
+
+        match self.attributes.as_ref()? {
+            Attributes::ActivityTaskScheduledEventAttributes(_) => Some(self.id),
+            Attributes::ActivityTaskStartedEventAttributes(attrs) => Some(attrs.scheduled_event_id),
+            Attributes::ActivityTaskCompletedEventAttributes(attrs) => {
+                Some(attrs.scheduled_event_id)
+            }
+            Attributes::ActivityTaskFailedEventAttributes(attrs) => Some(attrs.scheduled_event_id),
+            Attributes::ActivityTaskTimedOutEventAttributes(attrs) => {
+                Some(attrs.scheduled_event_id)
+            }
+            Attributes::ActivityTaskCancelRequestedEventAttributes(attrs) => {
+                Some(attrs.scheduled_event_id)
+            }
+            Attributes::ActivityTaskCanceledEventAttributes(attrs) => {
+                Some(attrs.scheduled_event_id)
+            }
+            _ => None,
+        }
+    }
+
+    /// ID of the run started to continue the workflow after this event closed it, like to retry
+    /// it or when it continued as new, if any.
+    pub fn new_execution_run_id(&self) -> Option<&str> {
+        use history::history_event::Attributes;
+
+        let run_id = match self.attributes.as_ref()? {
+            Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs) => {
+                &attrs.new_execution_run_id
+            }
+            Attributes::WorkflowExecutionTimedOutEventAttributes(attrs) => {
+                &attrs.new_execution_run_id
+            }
+            Attributes::WorkflowExecutionCompletedEventAttributes(attrs) => {
+                &attrs.new_execution_run_id
+            }
+            Attributes::WorkflowExecutionFailedEventAttributes(attrs) => {
+                &attrs.new_execution_run_id
+            }
+            _ => return None,
+        };
+
+        if run_id.is_empty() {
+            None
+        } else {
+            Some(run_id)
+        }
+    }
+
+    /// ID and run ID, when already started, of the child workflow this event is about, if any.
+    pub fn child_workflow_execution(&self) -> Option<(&str, Option<&str>)> {
+        use history::history_event::Attributes;
+
+        let execution = match self.attributes.as_ref()? {
+            Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(attrs) => {
+                return Some((&attrs.workflow_id, None));
+            }
+            Attributes::ChildWorkflowExecutionStartedEventAttributes(attrs) => {
+                attrs.workflow_execution.as_ref()
+            }
+            Attributes::ChildWorkflowExecutionCompletedEventAttributes(attrs) => {
+                attrs.workflow_execution.as_ref()
+            }
+            Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs) => {
+                attrs.workflow_execution.as_ref()
+            }
+            _ => None,
+        }?;
+
+        Some((&execution.workflow_id, Some(execution.run_id.as_str())))
+    }
+
+    /// The workflow execution this event links to, by its ID and run ID: a new run of the
+    /// workflow with ID `workflow_id` that this event belongs to, or a child workflow.
+    pub fn linked_execution<'a>(
+        &'a self,
+        workflow_id: &'a str,
+    ) -> Option<(&'a str, Option<&'a str>)> {
+        match self.new_execution_run_id() {
+            Some(run_id) => Some((workflow_id, Some(run_id))),
+            None => self.child_workflow_execution(),
+        }
+    }
+
+    /// The failure recorded by this event, if any.
+    pub fn failure(&self) -> Option<&failure::Failure> {
+        use history::history_event::Attributes;
+
+        match self.attributes.as_ref()? {
+            Attributes::WorkflowExecutionFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+            Attributes::WorkflowTaskFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+            Attributes::ActivityTaskFailedEventAttributes(attrs) => attrs.failure.as_ref(),
+            Attributes::ActivityTaskTimedOutEventAttributes(attrs) => attrs.failure.as_ref(),
+            Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs) => {
+                attrs.failure.as_ref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this event is worth summarizing: failures and events changing the workflow's state.
+    pub fn is_key_event(&self) -> bool {
+        self.is_failure()
+            || matches!(
+                self.r#type,
+                enums::EventType::WorkflowExecutionStarted
+                    | enums::EventType::WorkflowExecutionCompleted
+                    | enums::EventType::WorkflowExecutionCanceled
+                    | enums::EventType::WorkflowExecutionCancelRequested
+                    | enums::EventType::WorkflowExecutionTerminated
+                    | enums::EventType::WorkflowExecutionContinuedAsNew
+                    | enums::EventType::WorkflowExecutionSignaled
+            )
+    }
+
+    /// Whether this event's type is one of `noise_event_types`, normalized by [`normalize_event_type`].
+    pub fn is_noise(&self, noise_event_types: &[String]) -> bool {
+        let event_type = normalize_event_type(self.r#type.as_str_name());
+        noise_event_types.contains(&event_type)
+    }
+
+    /// Whether this event records a failure or a timeout of some task, child or the workflow itself.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.r#type,
+            enums::EventType::WorkflowExecutionFailed
+                | enums::EventType::WorkflowExecutionTimedOut
+                | enums::EventType::WorkflowTaskFailed
+                | enums::EventType::WorkflowTaskTimedOut
+                | enums::EventType::ActivityTaskFailed
+                | enums::EventType::ActivityTaskTimedOut
+                | enums::EventType::StartChildWorkflowExecutionFailed
+                | enums::EventType::ChildWorkflowExecutionFailed
+                | enums::EventType::ChildWorkflowExecutionTimedOut
+                | enums::EventType::RequestCancelExternalWorkflowExecutionFailed
+                | enums::EventType::SignalExternalWorkflowExecutionFailed
+                | enums::EventType::NexusOperationFailed
+                | enums::EventType::NexusOperationTimedOut
+        )
+    }
+
     pub fn type_as_string(&self) -> String {
         self.r#type
             .as_str_name()
@@ -279,613 +945,205 @@ impl EventWidget {
 
 impl widgets::Widget for &EventWidget {
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
-        if let Some(inner) = self.attributes.as_ref() {
-            match inner {
-                history::history_event::Attributes::WorkflowExecutionStartedEventAttributes(
-                    attrs,
-                ) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(9),
-                        layout::Constraint::Fill(1),
-                    ])
-                    .split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Workflow type name: ".into(),
-                            text::Span::from(&attrs.workflow_type.as_ref().unwrap().name),
-                        ]),
-                        text::Line::from(vec![
-                            "Task queue name: ".into(),
-                            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
-                        ]),
-                        text::Line::from(vec![
-                            "Task queue kind: ".into(),
-                            text::Span::from(match attrs.task_queue.as_ref().unwrap().kind {
-                                1 => enums::TaskQueueKind::Unspecified {}.as_str_name(),
-                                2 => enums::TaskQueueKind::Normal {}.as_str_name(),
-                                _ => enums::TaskQueueKind::Sticky {}.as_str_name(),
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Workflow task timeout: ".into(),
-                            text::Span::from(match attrs.workflow_task_timeout {
-                                Some(dur) => {
-                                    format!("{}s", time::Duration::try_from(dur).unwrap().as_secs())
-                                }
-                                None => "-".to_owned(),
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Attempt: ".into(),
-                            text::Span::from(format!("{}", attrs.attempt)),
-                        ]),
-                        text::Line::from(vec![
-                            "Original execution run ID: ".into(),
-                            text::Span::from(&attrs.original_execution_run_id),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "First execution run ID: ".into(),
-                            text::Span::from(&attrs.first_execution_run_id),
-                        ]),
-                        text::Line::from(vec![
-                            "Workflow ID: ".into(),
-                            text::Span::from(&attrs.workflow_id),
-                        ]),
-                    ];
-
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(payloads) = attrs.input.as_ref() {
-                        for p in payloads.payloads.iter().take(1) {
-                            let payload = PayloadWidget::cloned(p, "Input", self.theme);
-                            payload.render(areas[1], buf);
-                        }
-                    }
-                }
-                history::history_event::Attributes::WorkflowTaskScheduledEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Task queue name: ".into(),
-                            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
-                        ]),
-                        text::Line::from(vec![
-                            "Task queue kind: ".into(),
-                            text::Span::from(match attrs.task_queue.as_ref().unwrap().kind {
-                                1 => enums::TaskQueueKind::Unspecified {}.as_str_name(),
-                                2 => enums::TaskQueueKind::Normal {}.as_str_name(),
-                                _ => enums::TaskQueueKind::Sticky {}.as_str_name(),
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Start to close timeout: ".into(),
-                            text::Span::from(if let Some(dur) = attrs.start_to_close_timeout {
-                                format!("{}s", time::Duration::try_from(dur).unwrap().as_secs())
-                            } else {
-                                "-".to_owned()
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Attempt: ".into(),
-                            text::Span::from(format!("{}", attrs.attempt)),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowTaskStartedEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.scheduled_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Request ID: ".into(),
-                            text::Span::from(&attrs.request_id),
-                        ]),
-                        text::Line::from(vec![
-                            "History size bytes: ".into(),
-                            text::Span::from(format!("{}", &attrs.history_size_bytes)),
-                        ]),
-                        text::Line::from(vec![
-                            "Worker version: ".into(),
-                            text::Span::from(match &attrs.worker_version {
-                                Some(ts) => &ts.build_id,
-                                None => "-",
-                            }),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowTaskCompletedEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.scheduled_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Started event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.started_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Worker version: ".into(),
-                            text::Span::from(if let Some(ts) = &attrs.worker_version {
-                                &ts.build_id
-                            } else {
-                                "-"
-                            }),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowTaskFailedEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(4),
-                        layout::Constraint::Fill(1),
-                    ]).split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.scheduled_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Started event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.started_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Worker version: ".into(),
-                            text::Span::from(if let Some(ts) = &attrs.worker_version {
-                                &ts.build_id
-                            } else {
-                                "-"
-                            }),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(failure) = &attrs.failure {
-                        let failure = FailureWidget::from(failure);
-                        failure.render(areas[1], buf);
-                    }
-                }
-                history::history_event::Attributes::WorkflowTaskTimedOutEventAttributes(attrs) => {
-                    let timeout_type = match attrs.timeout_type {
-                        1 => enums::TimeoutType::StartToClose,
-                        2 => enums::TimeoutType::ScheduleToStart,
-                        3 => enums::TimeoutType::ScheduleToClose,
-                        4 => enums::TimeoutType::Heartbeat,
-                        _ => enums::TimeoutType::Unspecified,
-                    };
+        renderers::render(self, area, buf);
+    }
+}
 
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.scheduled_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Started event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.started_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Timeout type: ".into(),
-                            text::Span::from(timeout_type.as_str_name()),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowExecutionCompletedEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(1),
-                        layout::Constraint::Fill(1),
-                    ]).split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Workflow task completed event ID: ".into(),
-                            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(payloads) = attrs.result.as_ref() {
-                        for p in payloads.payloads.iter().take(1) {
-                            let payload = PayloadWidget::cloned(p, "Result", self.theme);
-                            payload.render(areas[1], buf);
-                        }
-                    }
-                }
-                history::history_event::Attributes::WorkflowExecutionCancelRequestedEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Cause: ".into(),
-                            text::Span::from(&attrs.cause),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowExecutionCanceledEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Workflow task completed event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.workflow_task_completed_event_id)),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::WorkflowExecutionFailedEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(2),
-                        layout::Constraint::Fill(1),
-                    ])
-                    .split(area);
-
-                    let retry_state = match attrs.retry_state {
-                        1 => enums::RetryState::InProgress,
-                        2 => enums::RetryState::NonRetryableFailure,
-                        3 => enums::RetryState::Timeout,
-                        4 => enums::RetryState::MaximumAttemptsReached,
-                        5 => enums::RetryState::RetryPolicyNotSet,
-                        6 => enums::RetryState::InternalServerError,
-                        7 => enums::RetryState::CancelRequested,
-                        _ => enums::RetryState::Unspecified,
-                    };
+const MIN_SPLIT_PERCENTAGE: u16 = 20;
+const MAX_SPLIT_PERCENTAGE: u16 = 80;
+const SPLIT_PERCENTAGE_STEP: u16 = 10;
 
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Retry state: ".into(),
-                            text::Span::from(retry_state.as_str_name()),
-                        ]),
-                        text::Line::from(vec![
-                            "Workflow task completed event ID: ".into(),
-                            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(failure) = &attrs.failure {
-                        let failure = FailureWidget::from(failure);
-                        failure.render(areas[1], buf);
-                    }
-                }
-                history::history_event::Attributes::ActivityTaskScheduledEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(10),
-                        layout::Constraint::Fill(1),
-                        layout::Constraint::Fill(1),
-                        layout::Constraint::Fill(1),
-                    ])
-                    .split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Activity ID: ".into(),
-                            text::Span::from(&attrs.activity_id),
-                        ]),
-                        text::Line::from(vec![
-                            "Activity type: ".into(),
-                            text::Span::from(if let Some(activity_type) = &attrs.activity_type {
-                                &activity_type.name
-                            } else {
-                                "-"
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Task queue name: ".into(),
-                            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
-                        ]),
-                        text::Line::from(vec![
-                            "Task queue kind: ".into(),
-                            text::Span::from(match attrs.task_queue.as_ref().unwrap().kind {
-                                1 => enums::TaskQueueKind::Unspecified {}.as_str_name(),
-                                2 => enums::TaskQueueKind::Normal {}.as_str_name(),
-                                _ => enums::TaskQueueKind::Sticky {}.as_str_name(),
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Start to close timeout: ".into(),
-                            text::Span::from(if let Some(dur) = attrs.start_to_close_timeout {
-                                format!("{}s", time::Duration::try_from(dur).unwrap().as_secs())
-                            } else {
-                                "-".to_owned()
-                            }),
-                        ]),
-                        text::Line::from(vec![
-                            "Workflow task completed event ID: ".into(),
-                            text::Span::from(format!("{}", attrs.workflow_task_completed_event_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Use workflow build ID: ".into(),
-                            text::Span::from(format!("{}", attrs.use_workflow_build_id)),
-                        ]),
-                        text::Line::from(vec![
-                            "Retry policy initial interval: ".into(),
-                            text::Span::from(
-                                if let Some(retry_policy) = attrs.retry_policy.as_ref() {
-                                    if let Some(initial_interval) = retry_policy.initial_interval {
-                                        format!("{}", initial_interval)
-                                    } else {
-                                        "-".to_owned()
-                                    }
-                                } else {
-                                    "-".to_owned()
-                                },
-                            ),
-                        ]),
-                        text::Line::from(vec![
-                            "Retry policy backoff coefficient: ".into(),
-                            text::Span::from(
-                                if let Some(retry_policy) = attrs.retry_policy.as_ref() {
-                                    format!("{}", retry_policy.backoff_coefficient)
-                                } else {
-                                    "-".to_owned()
-                                },
-                            ),
-                        ]),
-                        text::Line::from(vec![
-                            "Retry policy maximum interval: ".into(),
-                            text::Span::from(
-                                if let Some(retry_policy) = attrs.retry_policy.as_ref() {
-                                    if let Some(maximum_interval) = retry_policy.maximum_interval {
-                                        format!("{}", maximum_interval)
-                                    } else {
-                                        "-".to_owned()
-                                    }
-                                } else {
-                                    "-".to_owned()
-                                },
-                            ),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(retry_policy) = attrs.retry_policy.as_ref() {
-                        // Using `collections::BTreeMap` for consistent order.
-                        let non_retryable_error_types: collections::BTreeMap<String, String> =
-                            retry_policy
-                                .non_retryable_error_types
-                                .iter()
-                                .enumerate()
-                                .map(|(i, e)| (format!("{}", i), e.to_string()))
-                                .collect();
-                        let pretty_non_retryable_error_types =
-                            serde_json::to_string_pretty(&non_retryable_error_types).unwrap();
-
-                        widgets::Paragraph::new(pretty_non_retryable_error_types)
-                            .block(
-                                widgets::Block::bordered()
-                                    .border_type(widgets::BorderType::Rounded)
-                                    .title("Retry policy non retryable error types".fg(self.theme.header_foreground))
-                                    .border_style(style::Style::new().fg(self.theme.border)),
-
-                            )
-                            .fg(self.theme.foreground)
-                            .wrap(widgets::Wrap { trim: false })
-                            .render(areas[1], buf);
-                    }
+#[derive(Debug, Clone)]
+pub struct HistoryWidget {
+    events: Vec<EventWidget>,
+    next_page_token: Option<Vec<u8>>,
+    theme: Theme,
+    display_event: Option<usize>,
+    /// Whether to render the selected event's details next to the history table.
+    split_detail: bool,
+    /// Percentage of the width taken by the history table when `split_detail` is enabled.
+    split_percentage: u16,
+    /// Whether to nest the events produced by each workflow task under it.
+    tree: bool,
+    /// IDs of `WorkflowTaskCompleted` events whose children are hidden in tree mode.
+    collapsed: collections::HashSet<i64>,
+    /// IDs of events the workflow can be reset to.
+    reset_points: collections::HashSet<i64>,
+    /// Only show events caused by this identity.
+    identity_filter: Option<String>,
+    /// Whether the server returned no history, like for archived workflows. Until then, an empty
+    /// history means it's still loading.
+    unavailable: bool,
+    /// Payloads larger than this are truncated when rendering event details.
+    max_payload_render_bytes: usize,
+    /// Whether to hide events of any of the `noise_event_types`.
+    hide_noise: bool,
+    /// Normalized types of internal events that clutter the history, see [`normalize_event_type`].
+    noise_event_types: Vec<String>,
+    /// Which of the displayed event's payloads is being navigated.
+    payload_index: usize,
+    /// Object keys and array indices followed into the navigated payload's value.
+    payload_path: Vec<String>,
+    /// Index of the selected child of the value at `payload_path`.
+    payload_field: usize,
+    /// IDs of the events marked to act on together, like exporting them.
+    marked: collections::BTreeSet<i64>,
+    /// How the times of events are displayed.
+    time_format: TimeFormat,
+    /// Text searched for in the types of events, whose matches are highlighted.
+    search: Option<String>,
+    /// Only show events of this category.
+    category: EventCategory,
+    /// For each event, the index of the `WorkflowTaskCompleted` event that produced it, if any.
+    parents: Vec<Option<usize>>,
+    /// Indexes of the `WorkflowTaskCompleted` events that produced other events.
+    workflow_tasks: collections::HashSet<usize>,
+    /// Indexes of the events displayed as rows, updated when the events or the filters change.
+    visible: Vec<usize>,
+}
 
-                    if let Some(header) = attrs.header.as_ref() {
-                        let headers: collections::HashMap<String, String> = header
-                            .fields
-                            .iter()
-                            .map(|(k, v)| {
-                                let payload = PayloadWidget::cloned(v, "Header", self.theme);
-                                (k.to_string(), payload.to_string_pretty())
-                            })
-                            .collect();
-                        let pretty_header = serde_json::to_string_pretty(&headers).unwrap();
-
-                        widgets::Paragraph::new(pretty_header)
-                            .block(
-                                widgets::Block::bordered()
-                                    .border_type(widgets::BorderType::Rounded)
-                                    .title("Header".fg(self.theme.header_foreground))
-                                    .border_style(style::Style::new().fg(self.theme.border)),
-                            )
-                            .fg(self.theme.foreground)
-                            .wrap(widgets::Wrap { trim: false })
-                            .render(areas[2], buf);
-                    }
+/// A category of events the history can be filtered down to.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum EventCategory {
+    #[default]
+    All,
+    Activity,
+    Timer,
+    Signal,
+}
 
-                    if let Some(payloads) = attrs.input.as_ref() {
-                        for p in payloads.payloads.iter().take(1) {
-                            let payload = PayloadWidget::cloned(p, "Input", self.theme);
-                            payload.render(areas[3], buf);
-                        }
-                    }
-                }
-                history::history_event::Attributes::ActivityTaskStartedEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.scheduled_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Request ID: ".into(),
-                            text::Span::from(&attrs.request_id),
-                        ]),
-                        text::Line::from(vec![
-                            "Attempt: ".into(),
-                            text::Span::from(attrs.attempt.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Worker version: ".into(),
-                            text::Span::from(match &attrs.worker_version {
-                                Some(ts) => &ts.build_id,
-                                None => "-",
-                            }),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::ActivityTaskCompletedEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(3),
-                        layout::Constraint::Fill(1),
-                    ])
-                    .split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.scheduled_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.started_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(payloads) = attrs.result.as_ref() {
-                        for p in payloads.payloads.iter().take(1) {
-                            let payload = PayloadWidget::cloned(p, "Result", self.theme);
-                            payload.render(areas[1], buf);
-                        }
-                    }
-                }
-                history::history_event::Attributes::ActivityTaskCancelRequestedEventAttributes(attrs) => {
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.scheduled_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Workflow task completed event ID: ".into(),
-                            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(area, buf);
-                }
-                history::history_event::Attributes::ActivityTaskCanceledEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(5),
-                        layout::Constraint::Fill(1),
-                    ])
-                        .split(area);
-
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Latest cancel requested event ID: ".into(),
-                            text::Span::from(attrs.latest_cancel_requested_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.scheduled_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Started event ID: ".into(),
-                            text::Span::from(attrs.started_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Worker version: ".into(),
-                            text::Span::from(if let Some(ts) = &attrs.worker_version {
-                                &ts.build_id
-                            } else {
-                                "-"
-                            }),
-                        ])
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
+impl EventCategory {
+    /// The category after this one, going back to all events after the last one.
+    fn next(self) -> Self {
+        match self {
+            EventCategory::All => EventCategory::Activity,
+            EventCategory::Activity => EventCategory::Timer,
+            EventCategory::Timer => EventCategory::Signal,
+            EventCategory::Signal => EventCategory::All,
+        }
+    }
 
-                    if let Some(payloads) = attrs.details.as_ref() {
-                        for p in payloads.payloads.iter().take(1) {
-                            let payload = PayloadWidget::cloned(p, "Details", self.theme);
-                            payload.render(areas[1], buf);
-                        }
-                    }
-                }
-                history::history_event::Attributes::ActivityTaskFailedEventAttributes(attrs) => {
-                    let areas = layout::Layout::vertical([
-                        layout::Constraint::Length(4),
-                        layout::Constraint::Fill(1),
-                    ])
-                    .split(area);
-
-                    let retry_state = match attrs.retry_state {
-                        1 => enums::RetryState::InProgress,
-                        2 => enums::RetryState::NonRetryableFailure,
-                        3 => enums::RetryState::Timeout,
-                        4 => enums::RetryState::MaximumAttemptsReached,
-                        5 => enums::RetryState::RetryPolicyNotSet,
-                        6 => enums::RetryState::InternalServerError,
-                        7 => enums::RetryState::CancelRequested,
-                        _ => enums::RetryState::Unspecified,
-                    };
+    /// How this category is named in the title of the history.
+    fn name(self) -> &'static str {
+        match self {
+            EventCategory::All => "all",
+            EventCategory::Activity => "activities",
+            EventCategory::Timer => "timers",
+            EventCategory::Signal => "signals",
+        }
+    }
 
-                    let lines = vec![
-                        text::Line::from(vec![
-                            "Identity: ".into(),
-                            text::Span::from(&attrs.identity),
-                        ]),
-                        text::Line::from(vec![
-                            "Retry state: ".into(),
-                            text::Span::from(retry_state.as_str_name()),
-                        ]),
-                        text::Line::from(vec![
-                            "Scheduled event ID: ".into(),
-                            text::Span::from(attrs.scheduled_event_id.to_string()),
-                        ]),
-                        text::Line::from(vec![
-                            "Started event ID: ".into(),
-                            text::Span::from(attrs.started_event_id.to_string()),
-                        ]),
-                    ];
-                    widgets::Paragraph::new(lines).fg(self.theme.foreground).render(areas[0], buf);
-
-                    if let Some(failure) = &attrs.failure {
-                        let failure = FailureWidget::from(failure);
-                        failure.render(areas[1], buf);
-                    }
-                }
-                _ => {}
-            }
-        };
+    fn contains(self, event: &EventWidget) -> bool {
+        match self {
+            EventCategory::All => true,
+            EventCategory::Activity => matches!(
+                event.r#type,
+                enums::EventType::ActivityTaskScheduled
+                    | enums::EventType::ActivityTaskStarted
+                    | enums::EventType::ActivityTaskCompleted
+                    | enums::EventType::ActivityTaskFailed
+                    | enums::EventType::ActivityTaskTimedOut
+                    | enums::EventType::ActivityTaskCancelRequested
+                    | enums::EventType::ActivityTaskCanceled
+            ),
+            EventCategory::Timer => matches!(
+                event.r#type,
+                enums::EventType::TimerStarted
+                    | enums::EventType::TimerFired
+                    | enums::EventType::TimerCanceled
+            ),
+            EventCategory::Signal => matches!(
+                event.r#type,
+                enums::EventType::WorkflowExecutionSignaled
+                    | enums::EventType::SignalExternalWorkflowExecutionInitiated
+                    | enums::EventType::SignalExternalWorkflowExecutionFailed
+                    | enums::EventType::ExternalWorkflowExecutionSignaled
+            ),
+        }
     }
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct HistoryWidget {
-    events: Vec<EventWidget>,
-    next_page_token: Option<Vec<u8>>,
-    theme: Theme,
-    display_event: Option<usize>,
+/// Normalize an event type name so that `WorkflowTaskScheduled` and
+/// `EVENT_TYPE_WORKFLOW_TASK_SCHEDULED` compare equal.
+fn normalize_event_type(event_type: &str) -> String {
+    event_type
+        .trim_start_matches("EVENT_TYPE_")
+        .replace('_', "")
+        .to_lowercase()
+}
+
+impl Default for HistoryWidget {
+    fn default() -> Self {
+        Self::new(Theme::default(), DEFAULT_MAX_PAYLOAD_RENDER_BYTES)
+    }
 }
 
 impl HistoryWidget {
+    fn new(theme: Theme, max_payload_render_bytes: usize) -> Self {
+        Self {
+            events: Vec::new(),
+            next_page_token: None,
+            theme,
+            display_event: None,
+            split_detail: false,
+            split_percentage: 50,
+            tree: false,
+            collapsed: collections::HashSet::new(),
+            reset_points: collections::HashSet::new(),
+            identity_filter: None,
+            unavailable: false,
+            max_payload_render_bytes,
+            hide_noise: false,
+            noise_event_types: Vec::new(),
+            payload_index: 0,
+            payload_path: Vec::new(),
+            payload_field: 0,
+            marked: collections::BTreeSet::new(),
+            time_format: TimeFormat::default(),
+            search: None,
+            category: EventCategory::default(),
+            parents: Vec::new(),
+            workflow_tasks: collections::HashSet::new(),
+            visible: Vec::new(),
+        }
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        for event in self.events.iter_mut() {
+            event.theme = theme;
+        }
+    }
+
+    /// Mark the event at `index`, or unmark it if already marked.
+    fn toggle_marked(&mut self, index: usize) {
+        let Some(event) = self.events.get(index) else {
+            return;
+        };
+        if !self.marked.remove(&event.id) {
+            self.marked.insert(event.id);
+        }
+    }
+
+    /// The marked events, in history order.
+    fn marked_events(&self) -> Vec<&EventWidget> {
+        self.events
+            .iter()
+            .filter(|event| self.marked.contains(&event.id))
+            .collect()
+    }
+
+    fn with_noise_event_types(mut self, noise_event_types: &[String]) -> Self {
+        self.noise_event_types = noise_event_types
+            .iter()
+            .map(|event_type| normalize_event_type(event_type))
+            .collect();
+        self
+    }
+
     fn clear(&mut self) {
         self.events.clear();
+        self.update_parents();
     }
 
     fn is_empty(&self) -> bool {
@@ -896,6 +1154,10 @@ impl HistoryWidget {
         self.events.len()
     }
 
+    fn has_more_pages(&self) -> bool {
+        self.next_page_token.is_some()
+    }
+
     fn extend_from_history(&mut self, history: history::History) {
         for history_event in history.events.into_iter() {
             if let Ok(event_type) = enums::EventType::try_from(history_event.event_type) {
@@ -907,18 +1169,148 @@ impl HistoryWidget {
                     r#type: event_type,
                     attributes: history_event.attributes,
                     theme: self.theme,
+                    max_payload_render_bytes: self.max_payload_render_bytes,
                 };
                 self.events.push(event);
             }
         }
+        self.update_parents();
+    }
+
+    /// Extend with the events of `history` that come after the event with ID `last_event_id`.
+    fn extend_from_history_after(&mut self, mut history: history::History, last_event_id: i64) {
+        history.events.retain(|e| e.event_id > last_event_id);
+        self.extend_from_history(history);
+    }
+
+    /// Kind of the task queue the last workflow task was scheduled on, which is sticky while a
+    /// worker has the workflow cached.
+    fn task_queue_kind(&self) -> Option<enums::TaskQueueKind> {
+        self.events.iter().rev().find_map(|event| {
+            let task_queue = match event.attributes.as_ref()? {
+                history::history_event::Attributes::WorkflowTaskScheduledEventAttributes(attrs) => {
+                    attrs.task_queue.as_ref()
+                }
+                history::history_event::Attributes::WorkflowExecutionStartedEventAttributes(
+                    attrs,
+                ) => attrs.task_queue.as_ref(),
+                _ => None,
+            }?;
+            enums::TaskQueueKind::try_from(task_queue.kind).ok()
+        })
+    }
+
+    fn last_event_id(&self) -> i64 {
+        self.events.last().map(|e| e.id).unwrap_or(0)
     }
 
     fn display_event_at(&mut self, index: usize) {
         self.display_event = Some(index);
+        self.reset_payload_navigation();
     }
 
     fn clear_display_event(&mut self) {
         self.display_event = None;
+        self.reset_payload_navigation();
+    }
+
+    fn reset_payload_navigation(&mut self) {
+        self.payload_index = 0;
+        self.payload_path.clear();
+        self.payload_field = 0;
+    }
+
+    /// The payload being navigated in the displayed event, with its decoded value.
+    fn navigated_payload(&self) -> Option<(PayloadWidget<'_>, serde_json::Value)> {
+        let event = self.events.get(self.display_event?)?;
+        let payload = event.payloads().into_iter().nth(self.payload_index)?;
+        let value = payload.value();
+        Some((payload, value))
+    }
+
+    /// The children of the value at `payload_path` in the navigated payload.
+    fn payload_children(&self) -> Vec<String> {
+        self.navigated_payload()
+            .and_then(|(_, value)| json_value_at(&value, &self.payload_path).map(json_children))
+            .unwrap_or_default()
+    }
+
+    /// Move to another payload of the displayed event, wrapping around.
+    fn next_payload(&mut self) {
+        let count = self
+            .display_event
+            .and_then(|i| self.events.get(i))
+            .map(|event| event.payloads().len())
+            .unwrap_or(0);
+        if count > 0 {
+            self.payload_index = (self.payload_index + 1) % count;
+            self.payload_path.clear();
+            self.payload_field = 0;
+        }
+    }
+
+    /// Select the next or previous child of the value at `payload_path`, wrapping around.
+    fn move_payload_field(&mut self, forward: bool) {
+        let count = self.payload_children().len();
+        if count > 0 {
+            self.payload_field = if forward {
+                (self.payload_field + 1) % count
+            } else {
+                (self.payload_field + count - 1) % count
+            };
+        }
+    }
+
+    /// Follow the selected child into the value at `payload_path`.
+    fn enter_payload_field(&mut self) {
+        if let Some(child) = self.payload_children().get(self.payload_field) {
+            self.payload_path.push(child.clone());
+            self.payload_field = 0;
+        }
+    }
+
+    /// Go back up to the parent of the value at `payload_path`, keeping it selected.
+    fn leave_payload_field(&mut self) {
+        if let Some(child) = self.payload_path.pop() {
+            self.payload_field = self
+                .payload_children()
+                .iter()
+                .position(|c| *c == child)
+                .unwrap_or(0);
+        }
+    }
+
+    /// The value to copy: the selected child of the value at `payload_path`, or the whole
+    /// payload value when it has no children. Strings are copied without quotes.
+    fn payload_value_to_copy(&self) -> Option<String> {
+        let (_, value) = self.navigated_payload()?;
+        let parent = json_value_at(&value, &self.payload_path)?;
+        let selected = match json_children(parent).get(self.payload_field) {
+            Some(child) => json_value_at(parent, std::slice::from_ref(child))?,
+            None => parent,
+        };
+
+        Some(match selected {
+            serde_json::Value::String(s) => s.clone(),
+            other => serde_json::to_string_pretty(other).unwrap(),
+        })
+    }
+
+    /// A one-line summary of the payload navigation, like `Input $.order › id`.
+    fn payload_navigation_line(&self) -> Option<String> {
+        let (payload, _) = self.navigated_payload()?;
+        let path: String = self
+            .payload_path
+            .iter()
+            .map(|segment| format!(".{}", segment))
+            .collect();
+        let selected = self
+            .payload_children()
+            .get(self.payload_field)
+            .map(|child| format!(" › {}", child))
+            .unwrap_or_default();
+
+        Some(format!("{} ${}{}", payload.title, path, selected))
     }
 
     fn is_displaying_event(&self) -> bool {
@@ -927,21 +1319,347 @@ impl HistoryWidget {
             None => false,
         }
     }
+
+    fn toggle_split_detail(&mut self) {
+        self.split_detail = !self.split_detail;
+    }
+
+    fn is_split_detail(&self) -> bool {
+        self.split_detail
+    }
+
+    fn grow_split(&mut self) {
+        self.split_percentage =
+            (self.split_percentage + SPLIT_PERCENTAGE_STEP).min(MAX_SPLIT_PERCENTAGE);
+    }
+
+    fn shrink_split(&mut self) {
+        self.split_percentage = self
+            .split_percentage
+            .saturating_sub(SPLIT_PERCENTAGE_STEP)
+            .max(MIN_SPLIT_PERCENTAGE);
+    }
+
+    fn toggle_tree(&mut self) {
+        self.tree = !self.tree;
+        self.update_visible();
+    }
+
+    fn toggle_time_format(&mut self) {
+        self.time_format = self.time_format.toggle();
+    }
+
+    /// The time of `event` in the current [`TimeFormat`].
+    fn event_time(&self, event: &EventWidget) -> String {
+        match event.time {
+            Some(dt) => self.time_format.format(dt),
+            None => "-".to_owned(),
+        }
+    }
+
+    fn is_tree(&self) -> bool {
+        self.tree
+    }
+
+    /// Find the `WorkflowTaskCompleted` event that produced each event, after the events change.
+    fn update_parents(&mut self) {
+        let mut current_workflow_task: Option<(i64, usize)> = None;
+
+        self.parents = self
+            .events
+            .iter()
+            .enumerate()
+            .map(|(i, event)| {
+                if event.r#type == enums::EventType::WorkflowTaskCompleted {
+                    current_workflow_task = Some((event.id, i));
+                    return None;
+                }
+
+                match (
+                    current_workflow_task,
+                    event.workflow_task_completed_event_id(),
+                ) {
+                    (Some((id, parent)), Some(completed_id)) if id == completed_id => Some(parent),
+                    _ => None,
+                }
+            })
+            .collect();
+        self.workflow_tasks = self.parents.iter().flatten().copied().collect();
+        self.update_visible();
+    }
+
+    /// Indexes of the events that should be displayed as rows in the history table.
+    fn visible_indices(&self) -> &[usize] {
+        &self.visible
+    }
+
+    /// Find the events to display as rows, after the events or the filters change.
+    fn update_visible(&mut self) {
+        self.visible = self
+            .parents
+            .iter()
+            .enumerate()
+            .filter(|(_, parent)| match parent {
+                Some(p) if self.tree => !self.collapsed.contains(&self.events[*p].id),
+                _ => true,
+            })
+            .filter(|(i, _)| match self.identity_filter.as_ref() {
+                Some(identity) => self.events[*i].identity() == Some(identity.as_str()),
+                None => true,
+            })
+            .filter(|(i, _)| {
+                !(self.hide_noise && self.events[*i].is_noise(&self.noise_event_types))
+            })
+            .filter(|(i, _)| self.category.contains(&self.events[*i]))
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    /// Only show events caused by `identity`, or show all events if it's already filtered by.
+    fn toggle_identity_filter(&mut self, identity: &str) {
+        if self.identity_filter.as_deref() == Some(identity) {
+            self.identity_filter = None;
+        } else {
+            self.identity_filter = Some(identity.to_string());
+        }
+        self.update_visible();
+    }
+
+    fn clear_identity_filter(&mut self) {
+        self.identity_filter = None;
+        self.update_visible();
+    }
+
+    fn toggle_hide_noise(&mut self) {
+        self.hide_noise = !self.hide_noise;
+        self.update_visible();
+    }
+
+    fn cycle_category(&mut self) {
+        self.category = self.category.next();
+        self.update_visible();
+    }
+
+    /// Index of the next visible event after `index`, wrapping around to the first one if `wrap`
+    /// or else stopping at the last one. None when every event is filtered out.
+    fn next_visible(&self, index: Option<usize>, wrap: bool) -> Option<usize> {
+        let visible = self.visible_indices();
+        match index {
+            Some(i) => visible
+                .iter()
+                .find(|&&v| v > i)
+                .or(if wrap {
+                    visible.first()
+                } else {
+                    visible.last()
+                })
+                .copied(),
+            None => visible.first().copied(),
+        }
+    }
+
+    /// Index of the previous visible event before `index`, wrapping around to the last one if
+    /// `wrap` or else stopping at the first one. None when every event is filtered out.
+    fn previous_visible(&self, index: Option<usize>, wrap: bool) -> Option<usize> {
+        let visible = self.visible_indices();
+        match index {
+            Some(i) => visible
+                .iter()
+                .rev()
+                .find(|&&v| v < i)
+                .or(if wrap {
+                    visible.last()
+                } else {
+                    visible.first()
+                })
+                .copied(),
+            None => visible.first().copied(),
+        }
+    }
+
+    /// The title of the history, telling which filters are active.
+    fn title(&self) -> String {
+        let mut filters = Vec::new();
+        if let Some(identity) = self.identity_filter.as_ref() {
+            filters.push(format!("identity: {}", identity));
+        }
+        if self.hide_noise {
+            filters.push("noise hidden".to_string());
+        }
+        if self.category != EventCategory::All {
+            filters.push(format!("only {}", self.category.name()));
+        }
+
+        match filters.is_empty() {
+            true => "Event history".to_string(),
+            false => format!("Event history ({})", filters.join(", ")),
+        }
+    }
+
+    /// Byte range of the search in the type of `event`, ignoring case, if it matches.
+    fn search_match(&self, event: &EventWidget) -> Option<ops::Range<usize>> {
+        let search = self.search.as_ref()?.to_ascii_lowercase();
+        let start = event.type_as_string().to_ascii_lowercase().find(&search)?;
+        Some(start..start + search.len())
+    }
+
+    /// The type of `event` after `prefix`, with the part matching the search highlighted.
+    fn highlight_search<'a>(&self, event: &EventWidget, prefix: &'a str) -> text::Line<'a> {
+        let event_type = event.type_as_string();
+        match self.search_match(event) {
+            Some(range) => text::Line::from(vec![
+                text::Span::from(prefix),
+                text::Span::from(event_type[..range.start].to_string()),
+                text::Span::from(event_type[range.clone()].to_string())
+                    .fg(self.theme.selection_foreground)
+                    .bg(self.theme.selection_background),
+                text::Span::from(event_type[range.end..].to_string()),
+            ]),
+            None => text::Line::from(format!("{}{}", prefix, event_type)),
+        }
+    }
+
+    /// Index of the first visible event matching the search from `index` onwards, or backwards
+    /// if not `forward`, wrapping around when there are no more matches in that direction.
+    fn find_match(&self, index: Option<usize>, forward: bool) -> Option<usize> {
+        let matches: Vec<usize> = self
+            .visible_indices()
+            .iter()
+            .copied()
+            .filter(|&i| self.search_match(&self.events[i]).is_some())
+            .collect();
+        if forward {
+            matches
+                .iter()
+                .find(|&&i| index.is_none_or(|index| i >= index))
+                .or(matches.first())
+                .copied()
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| index.is_none_or(|index| i <= index))
+                .or(matches.last())
+                .copied()
+        }
+    }
+
+    /// Collapse or expand the workflow task at, or containing, `index`.
+    ///
+    /// Returns the index of the workflow task event, so it can be selected.
+    fn toggle_collapsed(&mut self, index: usize) -> Option<usize> {
+        let workflow_task = match self.parents.get(index)? {
+            Some(parent) => *parent,
+            None if self.workflow_tasks.contains(&index) => index,
+            None => return None,
+        };
+
+        let id = self.events[workflow_task].id;
+        if !self.collapsed.remove(&id) {
+            self.collapsed.insert(id);
+        }
+        self.update_visible();
+        Some(workflow_task)
+    }
+
+    /// Find the latest `WorkflowTaskFailed` event, as long as no workflow task has completed after it.
+    fn failing_workflow_task(
+        &self,
+    ) -> Option<(&EventWidget, &history::WorkflowTaskFailedEventAttributes)> {
+        for event in self.events.iter().rev() {
+            match event.attributes.as_ref() {
+                Some(history::history_event::Attributes::WorkflowTaskCompletedEventAttributes(
+                    _,
+                )) => return None,
+                Some(history::history_event::Attributes::WorkflowTaskFailedEventAttributes(
+                    attrs,
+                )) => return Some((event, attrs)),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+impl HistoryWidget {
+    /// Render a single event's time and type as a header, followed by its attributes.
+    fn render_event(&self, event: &EventWidget, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let selected_row_style = style::Style::default()
+            .add_modifier(style::Modifier::REVERSED)
+            .fg(self.theme.selection_background);
+
+        let header = [
+            widgets::Cell::new(self.event_time(event)),
+            widgets::Cell::new(event.type_as_string()),
+        ]
+        .into_iter()
+        .collect::<widgets::Row>()
+        .style(selected_row_style)
+        .height(1);
+
+        let single_row_table = widgets::Table::default()
+            .widths([
+                layout::Constraint::Length(24),
+                layout::Constraint::Length(32),
+            ])
+            .row_highlight_style(selected_row_style)
+            .bg(self.theme.background)
+            .highlight_spacing(widgets::HighlightSpacing::Always)
+            .header(header);
+
+        let vertical =
+            &layout::Layout::vertical([layout::Constraint::Length(1), layout::Constraint::Fill(1)]);
+        let [table_area, attributes_area] = vertical.areas(area);
+
+        widgets::Widget::render(single_row_table, table_area, buf);
+
+        event.render(attributes_area, buf);
+    }
 }
 
 impl widgets::StatefulWidget for &HistoryWidget {
     type State = widgets::TableState;
 
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer, state: &mut Self::State) {
-        let event_history_block = widgets::Block::bordered()
+        let mut event_history_block = widgets::Block::bordered()
             .border_type(widgets::BorderType::Rounded)
-            .title(text::Span::from("Event history".fg(self.theme.foreground)))
+            .title(self.title().fg(self.theme.foreground))
             .border_style(style::Style::new().fg(self.theme.border));
 
+        if let Some(search) = self.search.as_ref() {
+            event_history_block = event_history_block.title_bottom(
+                text::Line::from(format!("Search: {} (n/N for next/previous)", search))
+                    .fg(self.theme.foreground),
+            );
+        }
+
+        if !self.marked.is_empty() {
+            event_history_block = event_history_block.title(
+                text::Line::from(format!("{} marked", self.marked.len()))
+                    .right_aligned()
+                    .fg(self.theme.selection_foreground)
+                    .bg(self.theme.selection_background),
+            );
+        }
+
         let selected_row_style = style::Style::default()
             .add_modifier(style::Modifier::REVERSED)
             .fg(self.theme.selection_background);
 
+        if self.events.is_empty() {
+            let message = if self.unavailable {
+                "No history available for this workflow"
+            } else {
+                "Loading history..."
+            };
+            widgets::Paragraph::new(message)
+                .fg(self.theme.foreground)
+                .centered()
+                .block(event_history_block)
+                .render(area, buf);
+            return;
+        }
+
         match self.display_event {
             Some(index) => {
                 let displaying_event = self.events.get(index).unwrap();
@@ -949,58 +1667,80 @@ impl widgets::StatefulWidget for &HistoryWidget {
                 let inner_area = event_history_block.inner(area);
                 widgets::Widget::render(event_history_block, area, buf);
 
-                let header = [
-                    widgets::Cell::new(displaying_event.time_as_string()),
-                    widgets::Cell::new(displaying_event.type_as_string()),
-                ]
-                .into_iter()
-                .map(widgets::Cell::from)
-                .collect::<widgets::Row>()
-                .style(selected_row_style)
-                .height(1);
-
-                let single_row_table = widgets::Table::default()
-                    .widths([
-                        layout::Constraint::Length(24),
-                        layout::Constraint::Length(32),
-                    ])
-                    .row_highlight_style(selected_row_style)
-                    .bg(self.theme.background)
-                    .highlight_spacing(widgets::HighlightSpacing::Always)
-                    .header(header);
-
-                let vertical = &layout::Layout::vertical([
-                    layout::Constraint::Length(1),
-                    layout::Constraint::Fill(1),
-                ]);
-                let [table_area, attributes_area] = vertical.areas(inner_area);
-
-                widgets::Widget::render(single_row_table, table_area, buf);
-
-                displaying_event.render(attributes_area, buf);
+                match self.payload_navigation_line() {
+                    Some(line) => {
+                        let [event_area, navigation_area] = layout::Layout::vertical([
+                            layout::Constraint::Fill(1),
+                            layout::Constraint::Length(1),
+                        ])
+                        .areas(inner_area);
+                        self.render_event(displaying_event, event_area, buf);
+                        widgets::Paragraph::new(line)
+                            .fg(self.theme.header_foreground)
+                            .render(navigation_area, buf);
+                    }
+                    None => self.render_event(displaying_event, inner_area, buf),
+                }
             }
             None => {
-                let rows = self
-                    .events
+                let (table_area, detail_area) = if self.split_detail {
+                    let horizontal = &layout::Layout::horizontal([
+                        layout::Constraint::Percentage(self.split_percentage),
+                        layout::Constraint::Fill(1),
+                    ]);
+                    let [table_area, detail_area] = horizontal.areas(area);
+                    (table_area, Some(detail_area))
+                } else {
+                    (area, None)
+                };
+
+                let visible = self.visible_indices();
+
+                let rows = visible
                     .iter()
                     .enumerate()
-                    .map(|(i, event)| {
-                        let color = match i % 2 {
-                            0 => self.theme.background,
-                            _ => self.theme.alt_background,
+                    .map(|(i, &index)| {
+                        let event = &self.events[index];
+                        let (foreground, color) = match i % 2 {
+                            _ if self.marked.contains(&event.id) => (
+                                self.theme.selection_foreground,
+                                self.theme.selection_background,
+                            ),
+                            0 => (self.theme.foreground, self.theme.background),
+                            _ => (self.theme.foreground, self.theme.alt_background),
+                        };
+                        let prefix = if !self.tree {
+                            ""
+                        } else if self.parents[index].is_some() {
+                            "  └ "
+                        } else if self.workflow_tasks.contains(&index) {
+                            if self.collapsed.contains(&event.id) {
+                                "▸ "
+                            } else {
+                                "▾ "
+                            }
+                        } else {
+                            ""
+                        };
+                        let reset_point_marker = if self.reset_points.contains(&event.id) {
+                            "↺"
+                        } else {
+                            ""
                         };
                         widgets::Row::new(vec![
+                            widgets::Cell::new(reset_point_marker),
                             widgets::Cell::new(format!("{}", event.id)),
-                            widgets::Cell::new(event.time_as_string()),
-                            widgets::Cell::new(event.type_as_string()),
+                            widgets::Cell::new(self.event_time(event)),
+                            widgets::Cell::new(self.highlight_search(event, prefix)),
                         ])
-                        .style(style::Style::new().fg(self.theme.foreground).bg(color))
+                        .style(style::Style::new().fg(foreground).bg(color))
                         .height(1)
                     })
                     .collect::<Vec<widgets::Row>>();
                 let event_history_table = widgets::Table::new(
                     rows,
                     [
+                        layout::Constraint::Length(1),
                         layout::Constraint::Length(5),
                         layout::Constraint::Length(24),
                         layout::Constraint::Length(32),
@@ -1011,24 +1751,312 @@ impl widgets::StatefulWidget for &HistoryWidget {
                 .bg(self.theme.background)
                 .highlight_spacing(widgets::HighlightSpacing::Always);
 
-                widgets::StatefulWidget::render(event_history_table, area, buf, state);
-            }
+                // The table state selects an event index, which may not match its row when some
+                // events are hidden in tree mode.
+                let mut table_state = widgets::TableState::default()
+                    .with_offset(state.offset())
+                    .with_selected(
+                        state
+                            .selected()
+                            .and_then(|selected| visible.iter().position(|&i| i == selected)),
+                    );
+                widgets::StatefulWidget::render(
+                    event_history_table,
+                    table_area,
+                    buf,
+                    &mut table_state,
+                );
+                *state.offset_mut() = table_state.offset();
+
+                let mut scrollbar_state = widgets::ScrollbarState::new(visible.len())
+                    .position(table_state.selected().unwrap_or(0));
+                let scrollbar =
+                    widgets::Scrollbar::new(widgets::ScrollbarOrientation::VerticalRight)
+                        .begin_symbol(None)
+                        .end_symbol(None)
+                        .style(style::Style::new().fg(self.theme.border));
+                // Within the table's rounded corners
+                widgets::StatefulWidget::render(
+                    scrollbar,
+                    table_area.inner(layout::Margin {
+                        vertical: 1,
+                        horizontal: 0,
+                    }),
+                    buf,
+                    &mut scrollbar_state,
+                );
+
+                if let Some(detail_area) = detail_area {
+                    let event_detail_block = widgets::Block::bordered()
+                        .border_type(widgets::BorderType::Rounded)
+                        .title("Event details".fg(self.theme.foreground))
+                        .border_style(style::Style::new().fg(self.theme.border));
+
+                    let inner_area = event_detail_block.inner(detail_area);
+                    widgets::Widget::render(event_detail_block, detail_area, buf);
+
+                    if let Some(event) = state.selected().and_then(|i| self.events.get(i)) {
+                        self.render_event(event, inner_area, buf);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Where the [`WorkflowExecution`] displayed by a [`WorkflowWidget`] comes from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExecutionSource {
+    /// Sparse data from a list workflow executions response, as displayed in the table.
+    List,
+    /// Full data from a describe workflow execution response.
+    #[default]
+    Describe,
+}
+
+fn payloads_mut(
+    payloads: Option<&mut temporal_common::Payloads>,
+) -> impl Iterator<Item = &mut temporal_common::Payload> {
+    payloads
+        .into_iter()
+        .flat_map(|payloads| payloads.payloads.iter_mut())
+}
+
+fn memo_payloads_mut(
+    memo: Option<&mut temporal_common::Memo>,
+) -> impl Iterator<Item = &mut temporal_common::Payload> {
+    memo.into_iter().flat_map(|memo| memo.fields.values_mut())
+}
+
+/// The payloads of `failure` and of the failures that caused it: its encoded message and stack
+/// trace, and the details it was raised with.
+fn failure_payloads_mut(
+    failure: Option<&mut failure::Failure>,
+) -> Vec<&mut temporal_common::Payload> {
+    use failure::failure::FailureInfo;
+
+    let Some(failure) = failure else {
+        return Vec::new();
+    };
+    let details = match failure.failure_info.as_mut() {
+        Some(FailureInfo::ApplicationFailureInfo(info)) => info.details.as_mut(),
+        Some(FailureInfo::CanceledFailureInfo(info)) => info.details.as_mut(),
+        Some(FailureInfo::TimeoutFailureInfo(info)) => info.last_heartbeat_details.as_mut(),
+        Some(FailureInfo::ResetWorkflowFailureInfo(info)) => info.last_heartbeat_details.as_mut(),
+        _ => None,
+    };
+
+    failure
+        .encoded_attributes
+        .iter_mut()
+        .chain(payloads_mut(details))
+        .chain(failure_payloads_mut(failure.cause.as_deref_mut()))
+        .collect()
+}
+
+/// Every payload of `event` a data converter may have encoded, to decode them with the codec
+/// server. Headers and search attributes are left as they are, codecs don't encode them.
+fn event_payloads_mut(event: &mut history::HistoryEvent) -> Vec<&mut temporal_common::Payload> {
+    use history::history_event::Attributes;
+
+    match event.attributes.as_mut() {
+        Some(Attributes::WorkflowExecutionStartedEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut())
+                .chain(payloads_mut(attrs.last_completion_result.as_mut()))
+                .chain(memo_payloads_mut(attrs.memo.as_mut()))
+                .chain(failure_payloads_mut(attrs.continued_failure.as_mut()))
+                .collect()
+        }
+        Some(Attributes::WorkflowExecutionCompletedEventAttributes(attrs)) => {
+            payloads_mut(attrs.result.as_mut()).collect()
+        }
+        Some(Attributes::WorkflowExecutionFailedEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.failure.as_mut())
+        }
+        Some(Attributes::WorkflowTaskFailedEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.failure.as_mut())
+        }
+        Some(Attributes::ActivityTaskScheduledEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut()).collect()
+        }
+        Some(Attributes::ActivityTaskStartedEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.last_failure.as_mut())
+        }
+        Some(Attributes::ActivityTaskCompletedEventAttributes(attrs)) => {
+            payloads_mut(attrs.result.as_mut()).collect()
+        }
+        Some(Attributes::ActivityTaskFailedEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.failure.as_mut())
+        }
+        Some(Attributes::ActivityTaskTimedOutEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.failure.as_mut())
+        }
+        Some(Attributes::ActivityTaskCanceledEventAttributes(attrs)) => {
+            payloads_mut(attrs.details.as_mut()).collect()
+        }
+        Some(Attributes::MarkerRecordedEventAttributes(attrs)) => attrs
+            .details
+            .values_mut()
+            .flat_map(|payloads| payloads.payloads.iter_mut())
+            .chain(failure_payloads_mut(attrs.failure.as_mut()))
+            .collect(),
+        Some(Attributes::WorkflowExecutionSignaledEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut()).collect()
         }
+        Some(Attributes::WorkflowExecutionTerminatedEventAttributes(attrs)) => {
+            payloads_mut(attrs.details.as_mut()).collect()
+        }
+        Some(Attributes::WorkflowExecutionCanceledEventAttributes(attrs)) => {
+            payloads_mut(attrs.details.as_mut()).collect()
+        }
+        Some(Attributes::WorkflowExecutionContinuedAsNewEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut())
+                .chain(payloads_mut(attrs.last_completion_result.as_mut()))
+                .chain(memo_payloads_mut(attrs.memo.as_mut()))
+                .chain(failure_payloads_mut(attrs.failure.as_mut()))
+                .collect()
+        }
+        Some(Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut())
+                .chain(memo_payloads_mut(attrs.memo.as_mut()))
+                .collect()
+        }
+        Some(Attributes::ChildWorkflowExecutionCompletedEventAttributes(attrs)) => {
+            payloads_mut(attrs.result.as_mut()).collect()
+        }
+        Some(Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs)) => {
+            failure_payloads_mut(attrs.failure.as_mut())
+        }
+        Some(Attributes::ChildWorkflowExecutionCanceledEventAttributes(attrs)) => {
+            payloads_mut(attrs.details.as_mut()).collect()
+        }
+        Some(Attributes::SignalExternalWorkflowExecutionInitiatedEventAttributes(attrs)) => {
+            payloads_mut(attrs.input.as_mut()).collect()
+        }
+        Some(Attributes::NexusOperationScheduledEventAttributes(attrs)) => {
+            attrs.input.iter_mut().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Every payload of a describe response a data converter may have encoded: the memo, and the
+/// heartbeat details and last failures of pending activities.
+fn describe_payloads_mut(
+    response: &mut service::DescribeWorkflowExecutionResponse,
+) -> Vec<&mut temporal_common::Payload> {
+    let memo = response
+        .workflow_execution_info
+        .as_mut()
+        .and_then(|info| info.memo.as_mut());
+
+    memo_payloads_mut(memo)
+        .chain(response.pending_activities.iter_mut().flat_map(|activity| {
+            payloads_mut(activity.heartbeat_details.as_mut())
+                .chain(failure_payloads_mut(activity.last_failure.as_mut()))
+        }))
+        .collect()
+}
+
+/// Encode `json` into a payload like SDKs encode JSON values, which is also how
+/// [`PayloadWidget`] decodes them.
+fn json_payload(json: &str) -> temporal_common::Payload {
+    temporal_common::Payload {
+        metadata: collections::HashMap::from([("encoding".to_string(), b"json/plain".to_vec())]),
+        data: json.as_bytes().to_vec(),
     }
 }
 
+/// Input of a signal to send, its name first and then its JSON payload.
+#[derive(Debug, Clone)]
+struct SignalInput {
+    /// The name of the signal, once entered.
+    name: Option<String>,
+    input: QueryInput,
+}
+
+/// Restart an activity by resetting the workflow to the workflow task that scheduled it.
+#[derive(Debug, Clone)]
+pub struct ActivityRestart {
+    activity_id: String,
+    /// The WorkflowTaskCompleted event that scheduled the activity.
+    reset_event_id: i64,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Workflow {
     pending_activities: Vec<PendingActivity>,
+    pending_nexus_operations: Vec<PendingNexusOperation>,
     execution: Option<WorkflowExecution>,
+    execution_source: ExecutionSource,
     history: HistoryWidget,
     history_state: sync::Arc<sync::RwLock<widgets::TableState>>,
+    /// Whether to display the panel listing the workflow's reset points.
+    show_reset_points: bool,
+    /// Whether to display the panel listing the workflow's pending activities.
+    show_pending_activities: bool,
+    /// Whether to display the panel listing the workflow's memo and search attributes.
+    show_attributes: bool,
+    /// Whether to hide all header fields but the IDs, to make room for the history.
+    collapse_header: bool,
+    /// ID of the pending activity whose heartbeats are being polled.
+    watched_activity: Option<String>,
+    /// An activity restart waiting to be confirmed.
+    pending_restart: Option<ActivityRestart>,
+    /// The workflow's current run, when we are displaying an older one.
+    current_run_id: Option<String>,
+    /// The result of the last stack trace query, or why it failed, while it's displayed.
+    stack_trace: Option<String>,
+    /// Lines scrolled down the displayed `stack_trace`.
+    stack_trace_scroll: u16,
+    /// The last describe response as it was received, as JSON, to display it in full.
+    raw_describe: Option<String>,
+    /// Whether `raw_describe` is displayed over the history.
+    show_raw_describe: bool,
+    /// Lines scrolled down the displayed `raw_describe`.
+    raw_describe_scroll: u16,
+    /// Rows fitting in the history table when it was last rendered, to jump by pages.
+    history_page_height: sync::Arc<sync::RwLock<usize>>,
+    /// Where to move the selection once the page of history being loaded arrives.
+    pending_jump: Option<PendingJump>,
+    /// Raw responses of the last reload, only kept when running in debug mode. There's no
+    /// describe response while displaying data from the list.
+    raw_responses: Option<(
+        Option<service::DescribeWorkflowExecutionResponse>,
+        service::GetWorkflowExecutionHistoryResponse,
+    )>,
+}
+
+impl Workflow {
+    /// Whether moving the selection by `jump` needs more events than are loaded.
+    fn needs_next_page(&self, jump: PendingJump) -> bool {
+        let selected = self.history_state.read().unwrap().selected();
+        match jump {
+            PendingJump::NextRow => {
+                selected.is_some_and(|i| !self.history.is_empty() && i + 1 >= self.history.len())
+            }
+            PendingJump::NextPage => {
+                let page_height = (*self.history_page_height.read().unwrap()).max(1);
+                let remaining = self
+                    .history
+                    .visible_indices()
+                    .iter()
+                    .filter(|&&i| selected.is_none_or(|selected| i > selected))
+                    .count();
+                remaining < page_height
+            }
+            PendingJump::LastRow => true,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WorkflowWidget {
     temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
     sender: sync::Arc<Option<mpsc::Sender<Message>>>,
+    tasks: BackgroundTasks,
+    /// The task polling the heartbeats of the watched activity, aborted before watching another.
+    heartbeat_poller: sync::Arc<sync::Mutex<Option<task::AbortHandle>>>,
     theme: Theme,
     /// The ID of the workflow we are displaying.
     workflow_id: String,
@@ -1037,12 +2065,28 @@ pub struct WorkflowWidget {
     /// The actual workflow data
     workflow: sync::Arc<sync::RwLock<Workflow>>,
     last_reload: sync::Arc<sync::RwLock<Option<time::Instant>>>,
+    reload_queue: ReloadQueue,
     loading_state: sync::Arc<sync::RwLock<LoadingState>>,
+    settings: sync::Arc<Settings>,
+    /// Whether to expand the terminal event of a closed workflow after its first load.
+    auto_expand_terminal_event: bool,
+    /// Input of the reason to cancel the workflow for, while asking for it. Inputs are boxed, as
+    /// they're rarely there.
+    cancel_reason: Option<Box<QueryInput>>,
+    /// Input of a signal to send, while asking for it.
+    signal: Option<Box<SignalInput>>,
+    /// Input of the text to search for in the types of events, while typing it.
+    search: Option<QueryInput>,
+    /// When the workflow's IDs were last copied to the clipboard, to tell they were.
+    copied_at: Option<time::Instant>,
+    /// A key waiting for the next one to complete a sequence, like the first `g` of `gg`.
+    pending_key: Option<char>,
 }
 
 impl WorkflowWidget {
     pub fn new(
         temporal_client: &sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+        settings: &sync::Arc<Settings>,
         workflow_id: &str,
         run_id: Option<&str>,
         theme: Theme,
@@ -1050,13 +2094,42 @@ impl WorkflowWidget {
         Self {
             temporal_client: temporal_client.clone(),
             sender: sync::Arc::new(None),
+            tasks: BackgroundTasks::default(),
+            heartbeat_poller: sync::Arc::new(sync::Mutex::new(None)),
             theme,
             workflow_id: workflow_id.to_owned(),
             run_id: run_id.map(|s| s.to_owned()),
             last_reload: sync::Arc::new(sync::RwLock::new(None)),
-            workflow: sync::Arc::new(sync::RwLock::new(Workflow::default())),
+            reload_queue: ReloadQueue::default(),
+            workflow: sync::Arc::new(sync::RwLock::new(Workflow {
+                history: HistoryWidget::new(theme, settings.max_payload_render_bytes)
+                    .with_noise_event_types(&settings.noise_event_types),
+                ..Workflow::default()
+            })),
             loading_state: sync::Arc::new(sync::RwLock::new(LoadingState::Idle)),
+            settings: settings.clone(),
+            auto_expand_terminal_event: settings.auto_expand_terminal_event,
+            cancel_reason: None,
+            signal: None,
+            search: None,
+            copied_at: None,
+            pending_key: None,
+        }
+    }
+
+    /// A short name for the tab showing this workflow.
+    pub fn tab_title(&self) -> String {
+        self.workflow_id.clone()
+    }
+
+    /// Start with an execution from a list response, until it's replaced by a describe response.
+    pub fn with_execution(self, execution: WorkflowExecution) -> Self {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            workflow.execution = Some(execution);
+            workflow.execution_source = ExecutionSource::List;
         }
+        self
     }
 
     pub fn run(&mut self) {
@@ -1064,35 +2137,49 @@ impl WorkflowWidget {
         *sync::Arc::get_mut(&mut self.sender).unwrap() = Some(tx);
 
         let this = self.clone(); // clone the widget to pass to the background task
-        tokio::spawn(this.fetch_workflow(rx));
+        self.tasks.spawn(this.fetch_workflow(rx));
     }
 
     async fn fetch_workflow(mut self, mut receiver: mpsc::Receiver<Message>) {
         log::debug!(widget = "WorkflowWidget"; "Starting fetch_workflow loop");
         while let Some(message) = receiver.recv().await {
             match message {
+                Message::Reload if self.has_history() => {
+                    self.reload_queue.wait(&self.last_reload).await;
+                    log::debug!(widget = "WorfklowWidget"; "Reloading incrementally");
+                    self.set_loading_state(LoadingState::Loading);
+                    self.incremental_reload().await;
+                }
                 Message::Reload => {
+                    self.reload_queue.wait(&self.last_reload).await;
                     log::debug!(widget = "WorfklowWidget"; "Reloading");
                     self.set_loading_state(LoadingState::Loading);
-                    let describe_workflow_execution_result = self
-                        .temporal_client
-                        .describe_workflow_execution(self.workflow_id.clone(), self.run_id.clone())
-                        .await;
-
-                    let get_workflow_execution_history_result = self
-                        .temporal_client
-                        .get_workflow_execution_history(
-                            self.workflow_id.clone(),
-                            self.run_id.clone(),
-                            Vec::new(),
-                        )
-                        .await;
+                    // Data from the list is kept until describing is requested
+                    let describe_workflow_execution_result = match self.execution_source() {
+                        ExecutionSource::List => None,
+                        ExecutionSource::Describe => Some(self.describe_workflow_execution().await),
+                    };
+
+                    let get_workflow_execution_history_result =
+                        self.get_workflow_execution_history(Vec::new()).await;
 
                     match (
-                        describe_workflow_execution_result,
+                        describe_workflow_execution_result.transpose(),
                         get_workflow_execution_history_result,
                     ) {
-                        (Ok(r1), Ok(r2)) => self.on_reload(r1, r2),
+                        (Ok(r1), Ok(r2)) => {
+                            let is_described = r1.is_some();
+                            self.on_reload(r1, r2);
+                            if is_described {
+                                self.check_current_run().await;
+                            }
+
+                            if self.auto_expand_terminal_event {
+                                // Only done once, when first opening the workflow.
+                                self.auto_expand_terminal_event = false;
+                                self.expand_terminal_event().await;
+                            }
+                        }
                         (Err(e1), Err(e2)) => self.on_err(anyhow::anyhow!(
                             "fetch workflow requests failed: {}, {}",
                             e1.to_string(),
@@ -1111,38 +2198,101 @@ impl WorkflowWidget {
                 _ => {}
             }
             match message {
-                Message::LoadPage { page_token } => {
-                    log::debug!(widget = "WorfklowWidget"; "Loading page {:?}", page_token);
+                Message::Describe => {
+                    log::debug!(widget = "WorfklowWidget"; "Describing");
                     self.set_loading_state(LoadingState::Loading);
+                    let describe_workflow_execution_result =
+                        self.describe_workflow_execution().await;
 
-                    let get_workflow_execution_history_result = self
-                        .temporal_client
-                        .get_workflow_execution_history(
-                            self.workflow_id.clone(),
-                            self.run_id.clone(),
-                            page_token,
-                        )
-                        .await;
-
-                    match get_workflow_execution_history_result {
-                        Ok(response) => self.on_workflow_history_load(response, false),
+                    match describe_workflow_execution_result {
+                        Ok(response) => {
+                            self.on_workflow_execution_load(response);
+                            self.check_current_run().await;
+                            self.set_loading_state(LoadingState::Reloaded);
+                        }
                         Err(e) => self.on_err(anyhow::anyhow!(
-                            "get workflow execution history request failed: {}",
-                            e.to_string()
+                            "describe workflow execution request failed: {}",
+                            e
                         )),
                     }
                 }
+                Message::Cancel { reason } => {
+                    log::debug!(widget = "WorfklowWidget"; "Cancelling");
+                    self.set_loading_state(LoadingState::Loading);
+                    self.cancel_workflow_execution(reason).await;
+                }
+                Message::Signal { name, input } => {
+                    log::debug!(widget = "WorfklowWidget"; "Signalling {}", name);
+                    self.set_loading_state(LoadingState::Loading);
+                    self.signal_workflow_execution(name, input).await;
+                }
+                Message::StackTrace => {
+                    log::debug!(widget = "WorfklowWidget"; "Querying stack trace");
+                    self.set_loading_state(LoadingState::Loading);
+                    let stack_trace = self.query_stack_trace().await;
+                    let mut workflow = self.workflow.write().unwrap();
+                    workflow.stack_trace = Some(stack_trace);
+                    workflow.stack_trace_scroll = 0;
+                    drop(workflow);
+                    self.set_loading_state(LoadingState::Idle);
+                }
+                Message::LoadPage { page_token } => {
+                    let mut page_token = Some(page_token);
+                    while let Some(token) = page_token.take() {
+                        if !self.load_page(token).await {
+                            self.workflow.write().unwrap().pending_jump = None;
+                            break;
+                        }
+                        page_token = self.next_page_token_for_jump();
+                    }
+                    self.apply_pending_jump();
+                }
+                Message::SetTheme(theme) => self.set_theme(theme),
                 _ => {}
             }
         }
     }
 
+    /// Load a page of history after the loaded events, returning whether it loaded.
+    async fn load_page(&mut self, page_token: Vec<u8>) -> bool {
+        log::debug!(widget = "WorfklowWidget"; "Loading page {:?}", page_token);
+        self.set_loading_state(LoadingState::Loading);
+
+        let get_workflow_execution_history_result =
+            self.get_workflow_execution_history(page_token).await;
+
+        match get_workflow_execution_history_result {
+            Ok(response) => {
+                self.on_workflow_history_load(response, false);
+                self.set_loading_state(LoadingState::PageLoaded);
+                true
+            }
+            Err(e) => {
+                self.on_err(anyhow::anyhow!(
+                    "get workflow execution history request failed: {}",
+                    e.to_string()
+                ));
+                false
+            }
+        }
+    }
+
     fn on_reload(
         &mut self,
-        describe_workflow_response: service::DescribeWorkflowExecutionResponse,
+        describe_workflow_response: Option<service::DescribeWorkflowExecutionResponse>,
         get_workflow_history_response: service::GetWorkflowExecutionHistoryResponse,
     ) {
-        self.on_workflow_execution_load(describe_workflow_response);
+        if self.settings.debug {
+            let mut workflow = self.workflow.write().unwrap();
+            workflow.raw_responses = Some((
+                describe_workflow_response.clone(),
+                get_workflow_history_response.clone(),
+            ));
+        }
+
+        if let Some(describe_workflow_response) = describe_workflow_response {
+            self.on_workflow_execution_load(describe_workflow_response);
+        }
         self.on_workflow_history_load(get_workflow_history_response, true);
         self.set_loading_state(LoadingState::Reloaded);
         log::debug!(widget = "WorkflowWidget"; "Reloaded");
@@ -1152,6 +2302,9 @@ impl WorkflowWidget {
         &mut self,
         describe_workflow_response: service::DescribeWorkflowExecutionResponse,
     ) {
+        self.workflow.write().unwrap().raw_describe =
+            Some(to_pretty_json(&describe_workflow_response));
+
         let execution = match describe_workflow_response.workflow_execution_info {
             Some(info) => match WorkflowExecution::try_from(info) {
                 Ok(e) => e,
@@ -1185,9 +2338,33 @@ impl WorkflowWidget {
             }
         };
 
+        let pending_nexus_operations: Vec<PendingNexusOperation> = match describe_workflow_response
+            .pending_nexus_operations
+            .into_iter()
+            .map(PendingNexusOperation::try_from)
+            .collect()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                self.on_err(anyhow::anyhow!(
+                    "invalid workflow pending nexus operation: {}",
+                    e
+                ));
+                return;
+            }
+        };
+
         let mut workflow = self.workflow.write().unwrap();
+        workflow.history.reset_points = execution
+            .reset_points
+            .iter()
+            .filter(|reset_point| reset_point.resettable)
+            .map(|reset_point| reset_point.first_workflow_task_completed_id)
+            .collect();
         workflow.execution = Some(execution);
+        workflow.execution_source = ExecutionSource::Describe;
         workflow.pending_activities = pending_activities;
+        workflow.pending_nexus_operations = pending_nexus_operations;
     }
 
     fn on_workflow_history_load(
@@ -1207,12 +2384,23 @@ impl WorkflowWidget {
             workflow.history.next_page_token = None;
         }
 
-        if !get_workflow_history_response.next_page_token.is_empty() {
+        if get_workflow_history_response.next_page_token.is_empty() {
+            workflow.history.next_page_token = None;
+        } else {
             workflow.history.next_page_token = Some(get_workflow_history_response.next_page_token);
         }
 
-        if let Some(history) = get_workflow_history_response.history {
-            workflow.history.extend_from_history(history);
+        match get_workflow_history_response.history {
+            Some(history) => workflow.history.extend_from_history(history),
+            None => log::warn!(
+                widget = "WorkflowWidget",
+                method = "on_workflow_history_load";
+                "Response has no history",
+            ),
+        }
+
+        if clear {
+            workflow.history.unavailable = workflow.history.is_empty();
         }
 
         if !workflow.history.is_empty() && clear {
@@ -1220,149 +2408,1923 @@ impl WorkflowWidget {
         }
     }
 
-    fn on_err(&mut self, err: anyhow::Error) {
-        self.set_loading_state(LoadingState::Error(err.to_string()));
-        panic!("error");
+    fn has_history(&self) -> bool {
+        let workflow = self.workflow.read().unwrap();
+        !workflow.history.is_empty()
     }
 
-    fn set_loading_state(&mut self, loading_state: LoadingState) {
-        match loading_state {
-            LoadingState::Reloaded => {
-                let mut last_reload = self.last_reload.write().unwrap();
-                *last_reload = Some(time::Instant::now());
+    /// Reload the workflow execution and append any new history events, without clearing the
+    /// history that was already loaded.
+    ///
+    /// History is append-only, so if more pages remain to be loaded any new events will come with
+    /// them. Otherwise, the history is read again and only events we haven't seen yet are kept.
+    async fn incremental_reload(&mut self) {
+        if self.execution_source() == ExecutionSource::Describe {
+            let describe_workflow_execution_result = self.describe_workflow_execution().await;
+
+            match describe_workflow_execution_result {
+                Ok(response) => self.on_workflow_execution_load(response),
+                Err(e) => {
+                    self.on_err(anyhow::anyhow!(
+                        "describe workflow execution request failed: {}",
+                        e
+                    ));
+                    return;
+                }
             }
-            _ => {}
+        }
+
+        let (last_event_id, has_more_pages) = {
+            let workflow = self.workflow.read().unwrap();
+            (
+                workflow.history.last_event_id(),
+                workflow.history.has_more_pages(),
+            )
         };
-        let mut loading_state_lock = self.loading_state.write().unwrap();
-        *loading_state_lock = loading_state;
-    }
 
-    pub async fn reload(&self) {
-        let sender = self.sender.as_ref().clone();
-        sender.unwrap().send(Message::Reload).await.unwrap();
-    }
+        if !has_more_pages {
+            let mut page_token = Vec::new();
+            loop {
+                let get_workflow_execution_history_result =
+                    self.get_workflow_execution_history(page_token).await;
 
-    pub fn get_selected_history_event(&self) -> Option<usize> {
-        let workflow = self.workflow.read().unwrap();
-        let selected = workflow.history_state.read().unwrap().selected();
-        selected
+                match get_workflow_execution_history_result {
+                    Ok(response) => {
+                        page_token = response.next_page_token;
+                        if let Some(history) = response.history {
+                            let mut workflow = self.workflow.write().unwrap();
+                            workflow
+                                .history
+                                .extend_from_history_after(history, last_event_id);
+                        }
+                    }
+                    Err(e) => {
+                        self.on_err(anyhow::anyhow!(
+                            "get workflow execution history request failed: {}",
+                            e
+                        ));
+                        return;
+                    }
+                }
+
+                if page_token.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        self.set_loading_state(LoadingState::Reloaded);
+        log::debug!(widget = "WorkflowWidget"; "Reloaded incrementally");
     }
 
-    pub async fn next_row(&mut self) {
-        let on_last_row = self.is_on_last_row();
-        let loading_next = if on_last_row {
-            self.load_next_page().await
-        } else {
-            false
+    /// Load all remaining history pages of a closed workflow and expand its last event.
+    async fn expand_terminal_event(&mut self) {
+        let is_closed = {
+            let workflow = self.workflow.read().unwrap();
+            match workflow.execution.as_ref() {
+                Some(execution) => execution.status != enums::WorkflowExecutionStatus::Running,
+                None => false,
+            }
         };
-        log::debug!(widget = "WorkflowWidget"; "Will load next {}", loading_next);
+
+        if !is_closed {
+            return;
+        }
 
         loop {
-            let on_last_row = self.is_on_last_row();
-            if !on_last_row || !loading_next {
+            let next_page_token = {
+                let workflow = self.workflow.read().unwrap();
+                workflow.history.next_page_token.clone()
+            };
+            let Some(page_token) = next_page_token else {
                 break;
-            }
-            task::yield_now().await;
-        }
+            };
 
-        let history_state_selected = self.get_selected_history_event();
+            let get_workflow_execution_history_result =
+                self.get_workflow_execution_history(page_token).await;
 
-        let workflow = self.workflow.read().unwrap();
-        let i = match history_state_selected {
-            Some(i) => {
-                if i >= workflow.history.len() - 1 {
-                    0
-                } else {
-                    i + 1
+            match get_workflow_execution_history_result {
+                Ok(response) => self.on_workflow_history_load(response, false),
+                Err(e) => {
+                    self.on_err(anyhow::anyhow!(
+                        "get workflow execution history request failed: {}",
+                        e
+                    ));
+                    return;
                 }
             }
+        }
 
-            None => 0,
-        };
+        let mut workflow = self.workflow.write().unwrap();
+        if workflow.history.is_empty() {
+            return;
+        }
+        let last = workflow.history.len() - 1;
+        workflow.history_state.write().unwrap().select(Some(last));
+        workflow.history.display_event_at(last);
+    }
 
-        let mut history_state = workflow.history_state.write().unwrap();
-        history_state.select(Some(i));
-        // state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
+    /// Describe the workflow, with its payloads decoded by the codec server if one is configured.
+    async fn describe_workflow_execution(
+        &self,
+    ) -> Result<service::DescribeWorkflowExecutionResponse, tonic::Status> {
+        let mut response = self
+            .temporal_client
+            .describe_workflow_execution(self.workflow_id.clone(), self.run_id.clone())
+            .await?;
+
+        if codec::is_enabled() {
+            if let Err(e) = codec::decode(describe_payloads_mut(&mut response)).await {
+                log::warn!(widget = "WorkflowWidget"; "Failed to decode payloads with codec server: {}", e);
+            }
+        }
+        Ok(response)
     }
 
-    pub fn is_on_last_row(&self) -> bool {
-        log::debug!(widget = "WorkflowWidget", method = "is_on_last_row"; "Requesting read workflow lock");
-        let workflow = self.workflow.read().unwrap();
-        log::debug!(widget = "WorkflowWidget", method = "is_on_last_row"; "Read workflow lock obtained");
-        let history_state_selected = self.get_selected_history_event();
-        match history_state_selected {
-            Some(i) => {
-                if i >= workflow.history.len() - 1 {
-                    true
-                } else {
-                    false
-                }
+    /// Request cancelling the workflow and reload, so the cancel request shows up in the history.
+    async fn cancel_workflow_execution(&mut self, reason: String) {
+        let run_id = {
+            let workflow = self.workflow.read().unwrap();
+            workflow
+                .execution
+                .as_ref()
+                .map(|e| e.run_id.clone())
+                .or(self.run_id.clone())
+        };
+
+        match self
+            .temporal_client
+            .cancel_workflow_execution(
+                self.workflow_id.clone(),
+                run_id.clone(),
+                reason.clone(),
+                None,
+            )
+            .await
+        {
+            Ok(_) => {
+                log::info!(widget = "WorkflowWidget"; "Requested cancelling workflow {}", self.workflow_id);
+                audit::record(
+                    "Cancel",
+                    &self.workflow_id,
+                    run_id.as_deref(),
+                    Some(reason.as_str()).filter(|reason| !reason.is_empty()),
+                );
+                self.incremental_reload().await;
             }
-            None => false,
+            Err(e) => self.on_err(anyhow::anyhow!(
+                "request cancel workflow execution request failed: {}",
+                e
+            )),
         }
     }
 
-    pub async fn load_next_page(&self) -> bool {
-        let workflow = self.workflow.read().unwrap();
-        let next_page_token = workflow.history.next_page_token.as_ref().cloned();
-        if let Some(page_token) = next_page_token {
-            log::debug!(
-                widget = "WorkflowWidget",
-                method = "load_next_page";
-                "Loading next page with token {:?}", &page_token
-            );
+    /// Send the signal `name` with `input` as its JSON payload, and reload so the signal shows up
+    /// in the history.
+    async fn signal_workflow_execution(&mut self, name: String, input: Option<String>) {
+        let run_id = {
+            let workflow = self.workflow.read().unwrap();
+            workflow
+                .execution
+                .as_ref()
+                .map(|e| e.run_id.clone())
+                .or(self.run_id.clone())
+        };
+        let payloads = input.map(|input| temporal_common::Payloads {
+            payloads: vec![json_payload(&input)],
+        });
 
-            let sender = self.sender.as_ref().clone();
-            sender
-                .unwrap()
-                .send(Message::LoadPage { page_token })
-                .await
-                .unwrap();
-            true
-        } else {
-            false
+        match self
+            .temporal_client
+            .signal_workflow_execution(
+                self.workflow_id.clone(),
+                run_id.clone().unwrap_or_default(),
+                name.clone(),
+                payloads,
+                None,
+            )
+            .await
+        {
+            Ok(_) => {
+                log::info!(widget = "WorkflowWidget"; "Sent signal {} to workflow {}", name, self.workflow_id);
+                audit::record(
+                    &format!("Signal {}", name),
+                    &self.workflow_id,
+                    run_id.as_deref(),
+                    None,
+                );
+                self.incremental_reload().await;
+            }
+            Err(e) => self.on_err(anyhow::anyhow!(
+                "signal workflow execution request failed: {}",
+                e
+            )),
         }
     }
 
-    pub fn previous_row(&mut self) {
-        let history_state_selected = self.get_selected_history_event();
+    /// Query the stack trace of the workflow, like to find where it's stuck. Failures, like when
+    /// no worker is running to answer, are returned as the message to display instead.
+    async fn query_stack_trace(&self) -> String {
+        let run_id = {
+            let workflow = self.workflow.read().unwrap();
+            workflow
+                .execution
+                .as_ref()
+                .map(|e| e.run_id.clone())
+                .or(self.run_id.clone())
+        };
+        let query = query::WorkflowQuery {
+            query_type: "__stack_trace".to_string(),
+            query_args: None,
+            header: None,
+        };
 
-        let workflow = self.workflow.read().unwrap();
-        let i = match history_state_selected {
-            Some(i) => {
-                if i == 0 {
-                    workflow.history.len() - 1
-                } else {
-                    i - 1
-                }
+        let response = match self
+            .temporal_client
+            .query_workflow_execution(self.workflow_id.clone(), run_id.unwrap_or_default(), query)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                log::error!(widget = "WorkflowWidget"; "Failed to query stack trace: {}", e);
+                return format!("Failed to query the stack trace: {}", e.message());
             }
-            None => 0,
         };
 
-        let mut history_state = workflow.history_state.write().unwrap();
-        history_state.select(Some(i));
-        // state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
+        if let Some(rejected) = response.query_rejected {
+            let status = enums::WorkflowExecutionStatus::try_from(rejected.status)
+                .unwrap_or(enums::WorkflowExecutionStatus::Unspecified);
+            return format!("Query rejected, workflow is {}", status.as_str_name());
+        }
+
+        let mut payloads = response.query_result.unwrap_or_default().payloads;
+        if let Err(e) = codec::decode(payloads.iter_mut().collect()).await {
+            log::warn!(widget = "WorkflowWidget"; "Failed to decode stack trace: {}", e);
+        }
+        match payloads.first() {
+            // The stack trace is a JSON string, but show anything else as it is
+            Some(payload) => serde_json::from_slice::<String>(&payload.data)
+                .unwrap_or_else(|_| String::from_utf8_lossy(&payload.data).into_owned()),
+            None => "No stack trace returned".to_string(),
+        }
     }
 
-    pub fn is_displaying_history_event(&self) -> bool {
-        let workflow = self.workflow.read().unwrap();
-        workflow.history.is_displaying_event()
+    pub async fn request_stack_trace(&self) {
+        let sender = self.sender.as_ref().clone();
+        sender.unwrap().send(Message::StackTrace).await.unwrap();
     }
-}
 
-impl widgets::Widget for &WorkflowWidget {
-    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
-        let vertical =
-            &layout::Layout::vertical([layout::Constraint::Length(9), layout::Constraint::Fill(1)]);
-        let [header_area, body_area] = vertical.areas(area);
+    /// Handle a key while the stack trace is displayed.
+    fn handle_stack_trace_key(&mut self, key: event::KeyEvent) {
+        let mut workflow = self.workflow.write().unwrap();
+        match key {
+            key if self.settings.keybinds.matches(Action::NextRow, &key) => {
+                workflow.stack_trace_scroll = workflow.stack_trace_scroll.saturating_add(1);
+            }
+            key if self.settings.keybinds.matches(Action::PreviousRow, &key) => {
+                workflow.stack_trace_scroll = workflow.stack_trace_scroll.saturating_sub(1);
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Esc | event::KeyCode::Char('Q'),
+                ..
+            } => workflow.stack_trace = None,
+            _ => {}
+        }
+    }
 
-        let workflow = self.workflow.read().unwrap();
+    /// Display the full describe response over the history, or stop displaying it.
+    fn toggle_raw_describe(&mut self) {
+        let mut workflow = self.workflow.write().unwrap();
+        workflow.show_raw_describe = !workflow.show_raw_describe;
+        workflow.raw_describe_scroll = 0;
+    }
+
+    /// Handle a key while the full describe response is displayed.
+    fn handle_raw_describe_key(&mut self, key: event::KeyEvent) {
+        let mut workflow = self.workflow.write().unwrap();
+        match key {
+            key if self.settings.keybinds.matches(Action::NextRow, &key) => {
+                workflow.raw_describe_scroll = workflow.raw_describe_scroll.saturating_add(1);
+            }
+            key if self.settings.keybinds.matches(Action::PreviousRow, &key) => {
+                workflow.raw_describe_scroll = workflow.raw_describe_scroll.saturating_sub(1);
+            }
+            event::KeyEvent {
+                code: event::KeyCode::PageDown,
+                ..
+            } => {
+                let page_height = *workflow.history_page_height.read().unwrap() as u16;
+                workflow.raw_describe_scroll =
+                    workflow.raw_describe_scroll.saturating_add(page_height);
+            }
+            event::KeyEvent {
+                code: event::KeyCode::PageUp,
+                ..
+            } => {
+                let page_height = *workflow.history_page_height.read().unwrap() as u16;
+                workflow.raw_describe_scroll =
+                    workflow.raw_describe_scroll.saturating_sub(page_height);
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Esc | event::KeyCode::Char('D'),
+                ..
+            } => workflow.show_raw_describe = false,
+            _ => {}
+        }
+    }
+
+    /// Start asking for a signal to send.
+    pub fn ask_signal(&mut self) {
+        self.signal = Some(Box::new(SignalInput {
+            name: None,
+            input: QueryInput::new("Name of the signal to send, Enter to continue", self.theme),
+        }));
+    }
+
+    /// Handle a key while asking for a signal to send.
+    async fn handle_signal_key(&mut self, key: event::KeyEvent) {
+        let Some(signal) = self.signal.as_mut() else {
+            return;
+        };
+
+        match (key.code, signal.name.as_ref()) {
+            (event::KeyCode::Enter, None) => {
+                let name = signal.input.query();
+                if name.is_empty() {
+                    return;
+                }
+                signal.name = Some(name);
+                signal.input = QueryInput::new(
+                    "JSON payload of the signal (optional), Enter to send",
+                    self.theme,
+                )
+                .with_subject("JSON");
+            }
+            (event::KeyCode::Enter, Some(name)) => {
+                let input = signal.input.query();
+                if !input.is_empty() {
+                    if let Err(e) = serde_json::from_str::<serde_json::Value>(&input) {
+                        signal
+                            .input
+                            .set_error(&e.to_string(), e.column().saturating_sub(1));
+                        return;
+                    }
+                }
+
+                let message = Message::Signal {
+                    name: name.clone(),
+                    input: Some(input).filter(|input| !input.is_empty()),
+                };
+                self.signal = None;
+                let sender = self.sender.as_ref().clone();
+                sender.unwrap().send(message).await.unwrap();
+            }
+            (event::KeyCode::Esc, _) => self.signal = None,
+            _ => {
+                signal.input.handle_key(key).await;
+            }
+        }
+    }
+
+    /// The action in [`Keybindable::write_keybinds`] that `key` would start, if any.
+    pub fn write_action(&self, key: event::KeyEvent) -> Option<&'static str> {
+        if self.input().is_some() {
+            return None;
+        }
+
+        match key {
+            event::KeyEvent {
+                code: event::KeyCode::Char('x'),
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } => Some("Cancel workflow"),
+            event::KeyEvent {
+                code: event::KeyCode::Char('S'),
+                ..
+            } => Some("Send signal"),
+            event::KeyEvent {
+                code: event::KeyCode::Char('R'),
+                ..
+            } => Some("Restart activity"),
+            _ => None,
+        }
+    }
+
+    /// The input displayed above the history while asking for something, if any.
+    fn input(&self) -> Option<&QueryInput> {
+        self.cancel_reason
+            .as_deref()
+            .or(self.signal.as_ref().map(|signal| &signal.input))
+            .or(self.search.as_ref())
+    }
+
+    /// Start asking for the reason to cancel the workflow for.
+    pub fn ask_cancel_reason(&mut self) {
+        self.cancel_reason = Some(Box::new(QueryInput::new(
+            "Reason to cancel the workflow for (optional), Enter to send",
+            self.theme,
+        )));
+    }
+
+    /// Handle a key while asking for the reason to cancel the workflow for.
+    async fn handle_cancel_reason_key(&mut self, key: event::KeyEvent) {
+        match key.code {
+            event::KeyCode::Enter => {
+                if let Some(cancel_reason) = self.cancel_reason.take() {
+                    let sender = self.sender.as_ref().clone();
+                    sender
+                        .unwrap()
+                        .send(Message::Cancel {
+                            reason: cancel_reason.query(),
+                        })
+                        .await
+                        .unwrap();
+                }
+            }
+            event::KeyCode::Esc => self.cancel_reason = None,
+            _ => {
+                if let Some(cancel_reason) = self.cancel_reason.as_mut() {
+                    cancel_reason.handle_key(key).await;
+                }
+            }
+        }
+    }
+
+    /// Start asking for text to search for in the types of events.
+    pub fn ask_search(&mut self) {
+        self.search = Some(QueryInput::new(
+            "Search event types, Enter to keep the search",
+            self.theme,
+        ));
+    }
+
+    /// Handle a key while asking for text to search for, selecting the first match from the
+    /// selected event as it's typed.
+    async fn handle_search_key(&mut self, key: event::KeyEvent) {
+        match key.code {
+            event::KeyCode::Enter => {
+                self.search = None;
+            }
+            event::KeyCode::Esc => {
+                self.search = None;
+                self.workflow.write().unwrap().history.search = None;
+            }
+            _ => {
+                let Some(search) = self.search.as_mut() else {
+                    return;
+                };
+                search.handle_key(key).await;
+                let query = search.query();
+
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.search = Some(query).filter(|query| !query.is_empty());
+                let selected = workflow.history_state.read().unwrap().selected();
+                if let Some(i) = workflow.history.find_match(selected, true) {
+                    workflow.history_state.write().unwrap().select(Some(i));
+                }
+            }
+        }
+    }
+
+    /// Select the next event matching the search after the selected one, or the previous one if
+    /// not `forward`.
+    pub fn next_match(&mut self, forward: bool) {
+        let workflow = self.workflow.read().unwrap();
+        let selected = workflow.history_state.read().unwrap().selected();
+        let from = match (selected, forward) {
+            (Some(i), true) => Some(i + 1),
+            (Some(i), false) => i.checked_sub(1),
+            (None, _) => None,
+        };
+        if let Some(i) = workflow.history.find_match(from, forward) {
+            workflow.history_state.write().unwrap().select(Some(i));
+        }
+    }
+
+    /// When displaying a specific run, find out if the workflow has a newer current run, like
+    /// after continuing as new, to offer opening it instead.
+    async fn check_current_run(&self) {
+        let Some(run_id) = self.run_id.as_ref() else {
+            return;
+        };
+
+        let current_run_id = match self
+            .temporal_client
+            .describe_workflow_execution(self.workflow_id.clone(), None)
+            .await
+        {
+            Ok(response) => response
+                .workflow_execution_info
+                .and_then(|info| info.execution)
+                .map(|execution| execution.run_id)
+                .filter(|current_run_id| current_run_id != run_id),
+            Err(e) => {
+                log::warn!(
+                    widget = "WorkflowWidget",
+                    method = "check_current_run";
+                    "Failed to describe current run: {}", e
+                );
+                None
+            }
+        };
+
+        if let Some(current_run_id) = current_run_id.as_ref() {
+            log::info!(
+                widget = "WorkflowWidget",
+                run_id = run_id.as_str(),
+                current_run_id = current_run_id.as_str();
+                "Displaying a run that is not the current one"
+            );
+        }
+        self.workflow.write().unwrap().current_run_id = current_run_id;
+    }
+
+    /// Get a page of the workflow's history, with its payloads decoded by the codec server if
+    /// one is configured.
+    async fn get_workflow_execution_history(
+        &self,
+        page_token: Vec<u8>,
+    ) -> Result<service::GetWorkflowExecutionHistoryResponse, tonic::Status> {
+        let mut response = self
+            .temporal_client
+            .get_workflow_execution_history(
+                self.workflow_id.clone(),
+                self.run_id.clone(),
+                page_token,
+            )
+            .await?;
+
+        if let Some(history) = response.history.as_mut().filter(|_| codec::is_enabled()) {
+            let payloads: Vec<&mut temporal_common::Payload> = history
+                .events
+                .iter_mut()
+                .flat_map(event_payloads_mut)
+                .collect();
+            if let Err(e) = codec::decode(payloads).await {
+                log::warn!(widget = "WorkflowWidget"; "Failed to decode payloads with codec server: {}", e);
+            }
+        }
+        Ok(response)
+    }
+
+    fn on_err(&mut self, err: anyhow::Error) {
+        log::error!(widget = "WorkflowWidget"; "{}", err);
+        self.set_loading_state(LoadingState::Error(err.to_string()));
+    }
+
+    /// The error of the last failed request, until dismissed or replaced by a successful one.
+    fn error(&self) -> Option<String> {
+        match &*self.loading_state.read().unwrap() {
+            LoadingState::Error(e) => Some(e.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_loading_state(&mut self, loading_state: LoadingState) {
+        match loading_state {
+            LoadingState::Reloaded => {
+                let mut last_reload = self.last_reload.write().unwrap();
+                *last_reload = Some(time::Instant::now());
+            }
+            _ => {}
+        };
+        let mut loading_state_lock = self.loading_state.write().unwrap();
+        *loading_state_lock = loading_state;
+    }
+
+    /// Reload in the background, unless a reload is already waiting to start.
+    pub async fn reload(&self) {
+        if !self.reload_queue.push() {
+            log::debug!(widget = "WorkflowWidget"; "Reload already queued");
+            return;
+        }
+
+        let sender = self.sender.as_ref().clone();
+        sender.unwrap().send(Message::Reload).await.unwrap();
+    }
+
+    fn execution_source(&self) -> ExecutionSource {
+        self.workflow.read().unwrap().execution_source
+    }
+
+    /// Replace list-based execution data with a full describe, if not already done.
+    pub async fn describe(&self) {
+        if self.execution_source() == ExecutionSource::List {
+            let sender = self.sender.as_ref().clone();
+            sender.unwrap().send(Message::Describe).await.unwrap();
+        }
+    }
+
+    /// Switch to `theme`, here and in the background loop, which builds widgets with it.
+    pub async fn switch_theme(&mut self, theme: Theme) {
+        self.set_theme(theme);
+        if let Some(sender) = self.sender.as_ref() {
+            if let Err(e) = sender.send(Message::SetTheme(theme)).await {
+                log::warn!(widget = "WorkflowWidget"; "Failed to send theme to background loop: {}", e);
+            }
+        }
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        if let Some(cancel_reason) = self.cancel_reason.as_mut() {
+            cancel_reason.set_theme(theme);
+        }
+        if let Some(signal) = self.signal.as_mut() {
+            signal.input.set_theme(theme);
+        }
+        if let Some(search) = self.search.as_mut() {
+            search.set_theme(theme);
+        }
+
+        let mut workflow = self.workflow.write().unwrap();
+        workflow.history.set_theme(theme);
+        for activity in workflow.pending_activities.iter_mut() {
+            activity.theme = theme;
+        }
+    }
+
+    /// Stop loading in the background, once this widget is no longer displayed.
+    pub fn shutdown(&self) {
+        self.stop_polling_heartbeats();
+        self.tasks.shutdown();
+    }
+
+    /// Whether keys are being typed into an input, like the reason to cancel the workflow for.
+    pub fn is_typing(&self) -> bool {
+        self.input().is_some()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        let loading_state = self.loading_state.read().unwrap();
+        loading_state.is_busy()
+    }
+
+    pub fn busy_message(&self) -> Option<String> {
+        let loading_state = self.loading_state.read().unwrap();
+        let workflow = self.workflow.read().unwrap();
+        match (&*loading_state, workflow.pending_jump) {
+            (LoadingState::Loading, Some(PendingJump::LastRow)) => Some(format!(
+                "Loading every page... ({} events, Esc to stop)",
+                workflow.history.len()
+            )),
+            _ => loading_state.busy_message(),
+        }
+    }
+
+    pub fn get_selected_history_event(&self) -> Option<usize> {
+        let workflow = self.workflow.read().unwrap();
+        let selected = workflow.history_state.read().unwrap().selected();
+        selected
+    }
+
+    pub async fn next_row(&mut self) {
+        if !self.has_history() || self.jump_after_next_page(PendingJump::NextRow).await {
+            return;
+        }
+        self.select_next_row();
+    }
+
+    fn select_next_row(&mut self) {
+        let history_state_selected = self.get_selected_history_event();
+
+        let workflow = self.workflow.read().unwrap();
+        if let Some(i) = workflow
+            .history
+            .next_visible(history_state_selected, self.settings.wrap_navigation)
+        {
+            workflow.history_state.write().unwrap().select(Some(i));
+        }
+    }
+
+    pub async fn load_next_page(&self) -> bool {
+        let workflow = self.workflow.read().unwrap();
+        let next_page_token = workflow.history.next_page_token.as_ref().cloned();
+        if let Some(page_token) = next_page_token {
+            log::debug!(
+                widget = "WorkflowWidget",
+                method = "load_next_page";
+                "Loading next page with token {:?}", &page_token
+            );
+
+            let sender = self.sender.as_ref().clone();
+            sender
+                .unwrap()
+                .send(Message::LoadPage { page_token })
+                .await
+                .unwrap();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn previous_row(&mut self) {
+        if !self.has_history() {
+            return;
+        }
+        let history_state_selected = self.get_selected_history_event();
+
+        let workflow = self.workflow.read().unwrap();
+        if let Some(i) = workflow
+            .history
+            .previous_visible(history_state_selected, self.settings.wrap_navigation)
+        {
+            workflow.history_state.write().unwrap().select(Some(i));
+        }
+    }
+
+    /// Select the event a page below the selection, loading more pages of history first while
+    /// there are fewer events left than fit on a page.
+    pub async fn next_page(&mut self) {
+        if !self.jump_after_next_page(PendingJump::NextPage).await {
+            self.select_next_page();
+        }
+    }
+
+    fn select_next_page(&mut self) {
+        let workflow = self.workflow.read().unwrap();
+        let page_height = (*workflow.history_page_height.read().unwrap()).max(1);
+        let visible = workflow.history.visible_indices();
+        let mut history_state = workflow.history_state.write().unwrap();
+        let position = history_state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&i| i == selected))
+            .map(|position| position + page_height)
+            .unwrap_or(0);
+        if let Some(i) = visible.get(position).or(visible.last()) {
+            history_state.select(Some(*i));
+        }
+    }
+
+    /// Select the event a page above the selection, stopping at the first one.
+    pub fn previous_page(&mut self) {
+        let workflow = self.workflow.read().unwrap();
+        let page_height = (*workflow.history_page_height.read().unwrap()).max(1);
+        let visible = workflow.history.visible_indices();
+        let mut history_state = workflow.history_state.write().unwrap();
+        let position = history_state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&i| i == selected))
+            .unwrap_or(0)
+            .saturating_sub(page_height);
+        if let Some(i) = visible.get(position) {
+            history_state.select(Some(*i));
+        }
+    }
+
+    /// Load the next page of history to move the selection by `jump` once it arrives, if moving
+    /// it needs more events than are loaded. Returns whether the jump waits for the page.
+    async fn jump_after_next_page(&mut self, jump: PendingJump) -> bool {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            if workflow.pending_jump.is_some() {
+                // The page is already loading, move the selection by the latest key once it's in
+                workflow.pending_jump = Some(jump);
+                return true;
+            }
+            if !workflow.history.has_more_pages() || !workflow.needs_next_page(jump) {
+                return false;
+            }
+            workflow.pending_jump = Some(jump);
+        }
+
+        self.set_loading_state(LoadingState::Loading);
+        self.load_next_page().await;
+        true
+    }
+
+    /// The token of the page after the loaded events, while the pending jump needs more of them.
+    fn next_page_token_for_jump(&self) -> Option<Vec<u8>> {
+        let workflow = self.workflow.read().unwrap();
+        let jump = workflow.pending_jump?;
+        if !workflow.needs_next_page(jump) {
+            return None;
+        }
+        workflow.history.next_page_token.clone()
+    }
+
+    /// Move the selection as requested while the last page of history was loading.
+    fn apply_pending_jump(&mut self) {
+        let pending_jump = self.workflow.write().unwrap().pending_jump.take();
+        match pending_jump {
+            Some(PendingJump::NextRow) => self.select_next_row(),
+            Some(PendingJump::NextPage) => self.select_next_page(),
+            Some(PendingJump::LastRow) => self.select_last_row(),
+            None => {}
+        }
+    }
+
+    /// Select the first visible event.
+    pub fn first_row(&mut self) {
+        let workflow = self.workflow.read().unwrap();
+        if let Some(i) = workflow.history.visible_indices().first() {
+            workflow.history_state.write().unwrap().select(Some(*i));
+        }
+    }
+
+    /// Select the last visible event, loading every remaining page of history first. Pages are
+    /// loaded in the background until the last one, or until stopped with Esc.
+    pub async fn last_row(&mut self) {
+        if !self.jump_after_next_page(PendingJump::LastRow).await {
+            self.select_last_row();
+        }
+    }
+
+    /// Stop loading every page of history after the one in flight, selecting the last event
+    /// loaded so far. Returns whether pages were being loaded.
+    fn stop_loading_every_page(&mut self) -> bool {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            if workflow.pending_jump != Some(PendingJump::LastRow) {
+                return false;
+            }
+            workflow.pending_jump = None;
+        }
+        log::debug!(widget = "WorkflowWidget"; "Stopped loading every page");
+        self.select_last_row();
+        true
+    }
+
+    fn select_last_row(&mut self) {
+        let workflow = self.workflow.read().unwrap();
+        if let Some(i) = workflow.history.visible_indices().last() {
+            workflow.history_state.write().unwrap().select(Some(*i));
+        }
+    }
+
+    /// Select the next failure event after the current selection, wrapping around to the start.
+    pub fn next_failure_row(&mut self) {
+        let history_state_selected = self.get_selected_history_event();
+
+        let workflow = self.workflow.read().unwrap();
+        let len = workflow.history.len();
+        if len == 0 {
+            return;
+        }
+
+        let visible = workflow.history.visible_indices();
+        let start = history_state_selected.map(|i| i + 1).unwrap_or(0);
+        let next_failure = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|i| workflow.history.events[*i].is_failure() && visible.contains(i));
+
+        if let Some(i) = next_failure {
+            let mut history_state = workflow.history_state.write().unwrap();
+            history_state.select(Some(i));
+        }
+    }
+
+    /// Toggle filtering the history by `identity`, moving the selection to a visible event.
+    pub fn toggle_identity_filter(&mut self, identity: Option<String>) {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            match identity {
+                Some(identity) => workflow.history.toggle_identity_filter(&identity),
+                None => workflow.history.clear_identity_filter(),
+            }
+        }
+        self.select_visible_history_event();
+    }
+
+    /// Toggle hiding internal events, moving the selection to a visible event.
+    pub fn toggle_hide_noise(&mut self) {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            workflow.history.toggle_hide_noise();
+        }
+        self.select_visible_history_event();
+    }
+
+    /// Cycle through showing only activity, timer or signal events, and all events again.
+    pub fn cycle_event_category(&mut self) {
+        {
+            let mut workflow = self.workflow.write().unwrap();
+            workflow.history.cycle_category();
+        }
+        self.select_visible_history_event();
+    }
+
+    /// Move the selection to the first visible event if the selected one was filtered out.
+    fn select_visible_history_event(&mut self) {
+        let history_state_selected = self.get_selected_history_event();
+        let workflow = self.workflow.read().unwrap();
+
+        let visible = workflow.history.visible_indices();
+        let is_visible = history_state_selected.is_some_and(|i| visible.contains(&i));
+        if !is_visible {
+            let selected = visible.first().copied();
+            workflow.history_state.write().unwrap().select(selected);
+        }
+    }
+
+    /// Watch the next heartbeating pending activity, or stop watching after the last one.
+    pub fn watch_next_activity(&mut self) {
+        let watched_activity = {
+            let mut workflow = self.workflow.write().unwrap();
+            let heartbeating: Vec<String> = workflow
+                .pending_activities
+                .iter()
+                .filter(|activity| activity.is_heartbeating())
+                .map(|activity| activity.id.clone())
+                .collect();
+
+            workflow.watched_activity = match workflow.watched_activity.as_ref() {
+                Some(id) => heartbeating
+                    .iter()
+                    .skip_while(|activity_id| *activity_id != id)
+                    .nth(1)
+                    .cloned(),
+                None => heartbeating.first().cloned(),
+            };
+            workflow.watched_activity.clone()
+        };
+
+        // Only one activity is watched at a time, so its poller replaces the last one
+        self.stop_polling_heartbeats();
+        if watched_activity.is_some() {
+            let this = self.clone();
+            let poller = self.tasks.spawn(this.poll_heartbeats(watched_activity));
+            *self.heartbeat_poller.lock().unwrap() = Some(poller);
+        }
+    }
+
+    fn stop_polling_heartbeats(&self) {
+        if let Some(poller) = self.heartbeat_poller.lock().unwrap().take() {
+            poller.abort();
+        }
+    }
+
+    /// Periodically describe the workflow to refresh the heartbeats of `activity_id`, until it's
+    /// no longer being watched.
+    async fn poll_heartbeats(self, activity_id: Option<String>) {
+        let interval = time::Duration::from_secs(self.settings.heartbeat_poll_interval.max(1));
+
+        loop {
+            time::sleep(interval).await;
+
+            if self.workflow.read().unwrap().watched_activity != activity_id {
+                break;
+            }
+
+            let response = match self.describe_workflow_execution().await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::warn!(
+                        widget = "WorkflowWidget",
+                        method = "poll_heartbeats";
+                        "Failed to describe workflow: {}", e
+                    );
+                    continue;
+                }
+            };
+
+            let newer = response
+                .pending_activities
+                .into_iter()
+                .find(|info| Some(&info.activity_id) == activity_id.as_ref())
+                .and_then(|info| PendingActivity::new(info, self.theme).ok());
+
+            let mut workflow = self.workflow.write().unwrap();
+            let pending_activity = workflow
+                .pending_activities
+                .iter_mut()
+                .find(|activity| Some(&activity.id) == activity_id.as_ref());
+
+            match (pending_activity, newer) {
+                (Some(pending_activity), Some(newer)) => pending_activity.update_heartbeat(newer),
+                // The activity is no longer pending
+                _ => {
+                    workflow.watched_activity = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// The activity to restart: the one the selected (or displayed) event belongs to, or else
+    /// the watched activity.
+    fn activity_restart_target(&self) -> Option<ActivityRestart> {
+        use history::history_event::Attributes;
+
+        let selected = self.get_selected_history_event();
+        let workflow = self.workflow.read().unwrap();
+        let events = &workflow.history.events;
+
+        let scheduled_event = match workflow
+            .history
+            .display_event
+            .or(selected)
+            .and_then(|i| events.get(i))
+            .and_then(|event| event.activity_scheduled_event_id())
+        {
+            Some(scheduled_event_id) => events.iter().find(|e| e.id == scheduled_event_id),
+            None => {
+                let activity_id = workflow.watched_activity.as_ref()?;
+                events.iter().rev().find(|e| match e.attributes.as_ref() {
+                    Some(Attributes::ActivityTaskScheduledEventAttributes(attrs)) => {
+                        &attrs.activity_id == activity_id
+                    }
+                    _ => false,
+                })
+            }
+        }?;
+
+        match scheduled_event.attributes.as_ref()? {
+            Attributes::ActivityTaskScheduledEventAttributes(attrs) => Some(ActivityRestart {
+                activity_id: attrs.activity_id.clone(),
+                reset_event_id: attrs.workflow_task_completed_event_id,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Ask to confirm restarting the selected activity, or restart it if already asked to.
+    /// Returns a view of the new run the reset started.
+    pub async fn restart_activity(&mut self) -> Option<ViewWidget> {
+        let pending_restart = self.workflow.write().unwrap().pending_restart.take();
+        let Some(restart) = pending_restart else {
+            let target = self.activity_restart_target();
+            if target.is_none() {
+                log::info!(widget = "WorkflowWidget"; "No activity selected to restart");
+            }
+            self.workflow.write().unwrap().pending_restart = target;
+            return None;
+        };
+
+        let run_id = {
+            let workflow = self.workflow.read().unwrap();
+            workflow
+                .execution
+                .as_ref()
+                .map(|e| e.run_id.clone())
+                .or(self.run_id.clone())
+        };
+
+        match self
+            .temporal_client
+            .reset_workflow_execution(
+                self.workflow_id.clone(),
+                run_id.clone(),
+                restart.reset_event_id,
+                None,
+            )
+            .await
+        {
+            Ok(response) => {
+                log::info!(
+                    widget = "WorkflowWidget",
+                    activity_id = restart.activity_id.as_str(),
+                    reset_event_id = restart.reset_event_id;
+                    "Reset workflow to restart activity, new run {}", response.run_id
+                );
+                audit::record(
+                    &format!(
+                        "Reset to event {} to restart activity {}",
+                        restart.reset_event_id, restart.activity_id
+                    ),
+                    &self.workflow_id,
+                    run_id.as_deref(),
+                    None,
+                );
+                self.workflow.write().unwrap().watched_activity = None;
+                Some(ViewWidget::Workflow(WorkflowWidget::new(
+                    &self.temporal_client,
+                    &self.settings,
+                    &self.workflow_id,
+                    Some(&response.run_id),
+                    self.theme,
+                )))
+            }
+            Err(e) => {
+                log::error!(
+                    widget = "WorkflowWidget",
+                    activity_id = restart.activity_id.as_str();
+                    "Failed to reset workflow to restart activity: {}", e
+                );
+                None
+            }
+        }
+    }
+
+    /// Dump the raw responses of the last reload to the log file.
+    pub fn log_raw_responses(&self) {
+        let workflow = self.workflow.read().unwrap();
+        match workflow.raw_responses.as_ref() {
+            Some((describe_workflow_response, get_workflow_history_response)) => {
+                if let Some(describe_workflow_response) = describe_workflow_response {
+                    log::debug!(
+                        widget = "WorkflowWidget",
+                        method = "log_raw_responses";
+                        "DescribeWorkflowExecutionResponse: {:#?}", describe_workflow_response
+                    );
+                }
+                log::debug!(
+                    widget = "WorkflowWidget",
+                    method = "log_raw_responses";
+                    "GetWorkflowExecutionHistoryResponse: {:#?}", get_workflow_history_response
+                );
+            }
+            None => log::debug!(
+                widget = "WorkflowWidget",
+                method = "log_raw_responses";
+                "No raw responses to dump"
+            ),
+        }
+    }
+
+    /// Write the full payloads of the displayed (or, when split, selected) event to a file
+    /// next to the log file, as they may be too large to render.
+    pub fn export_event_payloads(&self) {
+        let selected = self.get_selected_history_event();
+        let workflow = self.workflow.read().unwrap();
+        let Some(event) = workflow
+            .history
+            .display_event
+            .or(selected.filter(|_| workflow.history.is_split_detail()))
+            .and_then(|i| workflow.history.events.get(i))
+        else {
+            return;
+        };
+
+        let payloads = event.payloads();
+        if payloads.is_empty() {
+            log::info!(widget = "WorkflowWidget", event_id = event.id; "Event has no payloads to export");
+            return;
+        }
+
+        let dumped = serde_json::json!({
+            "workflow_id": self.workflow_id,
+            "run_id": workflow.execution.as_ref().map(|e| e.run_id.as_str()),
+            "event_id": event.id,
+            "event_type": event.type_as_string(),
+            "payloads": payloads
+                .iter()
+                .map(|p| serde_json::json!({"title": p.title, "payload": p.to_json()}))
+                .collect::<Vec<_>>(),
+        });
+        let file_name = format!("{}-event-{}.json", self.workflow_id, event.id);
+        let path = self.export_path(&file_name);
+
+        match std::fs::write(&path, serde_json::to_string_pretty(&dumped).unwrap()) {
+            Ok(_) => {
+                log::info!(
+                    widget = "WorkflowWidget",
+                    event_id = event.id;
+                    "Exported event payloads to {}", path.display()
+                );
+                audit::record(
+                    &format!("Export event {} payloads to {}", event.id, path.display()),
+                    &self.workflow_id,
+                    workflow.execution.as_ref().map(|e| e.run_id.as_str()),
+                    None,
+                );
+            }
+            Err(e) => log::error!(
+                widget = "WorkflowWidget",
+                event_id = event.id;
+                "Failed to export event payloads to {}: {}", path.display(), e
+            ),
+        }
+    }
+
+    /// The marked events as pretty JSON, if any are marked.
+    fn marked_events_json(&self) -> Option<String> {
+        let workflow = self.workflow.read().unwrap();
+        let events = workflow.history.marked_events();
+        if events.is_empty() {
+            return None;
+        }
+
+        let dumped = serde_json::json!({
+            "workflow_id": self.workflow_id,
+            "run_id": workflow.execution.as_ref().map(|e| e.run_id.as_str()),
+            "events": events.iter().map(|event| event.to_json()).collect::<Vec<_>>(),
+        });
+        Some(serde_json::to_string_pretty(&dumped).unwrap())
+    }
+
+    /// Write the marked events to a file next to the log file.
+    pub fn export_marked_events(&self) {
+        let Some(dumped) = self.marked_events_json() else {
+            log::info!(widget = "WorkflowWidget"; "No events marked to export");
+            return;
+        };
+        let path = self.export_path(&format!("{}-events.json", self.workflow_id));
+
+        match std::fs::write(&path, dumped) {
+            Ok(_) => {
+                log::info!(widget = "WorkflowWidget"; "Exported marked events to {}", path.display());
+                let workflow = self.workflow.read().unwrap();
+                audit::record(
+                    &format!(
+                        "Export {} events to {}",
+                        workflow.history.marked.len(),
+                        path.display()
+                    ),
+                    &self.workflow_id,
+                    workflow.execution.as_ref().map(|e| e.run_id.as_str()),
+                    None,
+                );
+            }
+            Err(e) => log::error!(
+                widget = "WorkflowWidget";
+                "Failed to export marked events to {}: {}", path.display(), e
+            ),
+        }
+    }
+
+    /// Copy the marked events as JSON to the clipboard.
+    pub fn copy_marked_events(&self) {
+        let Some(dumped) = self.marked_events_json() else {
+            return;
+        };
+
+        match clipboard::copy(&dumped) {
+            Ok(_) => log::info!(widget = "WorkflowWidget"; "Copied marked events to clipboard"),
+            Err(e) => log::error!(widget = "WorkflowWidget"; "Failed to copy marked events: {}", e),
+        }
+    }
+
+    /// Copy the ID and run ID of the workflow to the clipboard, like to pass them to the Temporal
+    /// CLI.
+    pub fn copy_workflow(&mut self) {
+        let run_id = {
+            let workflow = self.workflow.read().unwrap();
+            workflow
+                .execution
+                .as_ref()
+                .map(|execution| execution.run_id.clone())
+                .or(self.run_id.clone())
+                .unwrap_or_default()
+        };
+
+        match clipboard::copy(format!("{} {}", self.workflow_id, run_id).trim_end()) {
+            Ok(_) => {
+                log::info!(widget = "WorkflowWidget"; "Copied workflow to clipboard");
+                self.copied_at = Some(time::Instant::now());
+            }
+            Err(e) => log::error!(widget = "WorkflowWidget"; "Failed to copy workflow: {}", e),
+        }
+    }
+
+    /// Copy the selected value of the displayed event's payload to the clipboard.
+    pub fn copy_payload_value(&self) {
+        let value = {
+            let workflow = self.workflow.read().unwrap();
+            workflow.history.payload_value_to_copy()
+        };
+        let Some(value) = value else {
+            return;
+        };
+
+        match clipboard::copy(&value) {
+            Ok(_) => log::info!(widget = "WorkflowWidget"; "Copied payload value to clipboard"),
+            Err(e) => log::error!(widget = "WorkflowWidget"; "Failed to copy payload value: {}", e),
+        }
+    }
+
+    /// Path of an exported file, next to the log file.
+    fn export_path(&self, file_name: &str) -> std::path::PathBuf {
+        let file_name = file_name.replace('/', "_");
+        self.settings
+            .log_path
+            .parent()
+            .map(|dir| dir.join(&file_name))
+            .unwrap_or(file_name.into())
+    }
+
+    /// Write a Markdown report of the workflow, its key events and failure, to a file next to
+    /// the log file, to be attached to incident tickets.
+    pub fn export_report(&self) {
+        let workflow = self.workflow.read().unwrap();
+        let Some(execution) = workflow.execution.as_ref() else {
+            return;
+        };
+        let cell = |value: &str| value.replace('|', "\\|");
+
+        let mut report = format!(
+            "# Workflow {}\n\nWorkflow `{}` of type `{}` is **{}**. It started at {} and ",
+            self.workflow_id,
+            self.workflow_id,
+            execution.r#type,
+            execution.status_as_string(),
+            execution.start_time_as_string(),
+        );
+        match execution.close_time {
+            Some(_) => report.push_str(&format!(
+                "closed at {} after {}.\n\n",
+                execution.close_time_as_string(),
+                execution.execution_duration_as_string(),
+            )),
+            None => report.push_str("has not closed yet.\n\n"),
+        }
+
+        report.push_str("| Field | Value |\n| --- | --- |\n");
+        for (key, value) in [
+            ("Workflow ID", self.workflow_id.clone()),
+            ("Run ID", execution.run_id.clone()),
+            ("Workflow type", execution.r#type.clone()),
+            ("Status", execution.status_as_string()),
+            ("Task queue", execution.task_queue.clone()),
+            ("Start", execution.start_time_as_string()),
+            ("Close", execution.close_time_as_string()),
+            ("Duration", execution.execution_duration_as_string()),
+            ("History length", execution.history_length.to_string()),
+            (
+                "History size (bytes)",
+                execution.history_size_bytes.to_string(),
+            ),
+        ] {
+            report.push_str(&format!("| {} | {} |\n", key, cell(&value)));
+        }
+
+        report.push_str("\n## Key events\n\n");
+        if !workflow.history.has_more_pages() {
+            report.push_str("The full history was loaded.\n\n");
+        } else {
+            report.push_str(&format!(
+                "Only the first {} events were loaded.\n\n",
+                workflow.history.len()
+            ));
+        }
+        report.push_str("| ID | Time | Type | Identity |\n| --- | --- | --- | --- |\n");
+        for event in workflow.history.events.iter().filter(|e| e.is_key_event()) {
+            report.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                event.id,
+                event.time_as_string(),
+                event.type_as_string(),
+                cell(event.identity().unwrap_or("-")),
+            ));
+        }
+
+        let last_failure = workflow
+            .history
+            .events
+            .iter()
+            .rev()
+            .find_map(|event| event.failure().map(|failure| (event, failure)));
+        if let Some((event, failure)) = last_failure {
+            report.push_str(&format!(
+                "\n## Failure\n\nThe last failure was recorded by event {} ({}), with source \
+                 `{}`:\n\n```\n{}\n```\n",
+                event.id,
+                event.type_as_string(),
+                failure.source,
+                failure.message,
+            ));
+            if !failure.stack_trace.is_empty() {
+                report.push_str(&format!(
+                    "\nStack trace:\n\n```\n{}\n```\n",
+                    failure.stack_trace
+                ));
+            }
+        }
+
+        let path = self.export_path(&format!("{}-report.md", self.workflow_id));
+        match std::fs::write(&path, report) {
+            Ok(_) => {
+                log::info!(
+                    widget = "WorkflowWidget";
+                    "Exported workflow report to {}", path.display()
+                );
+                audit::record(
+                    &format!("Export report to {}", path.display()),
+                    &self.workflow_id,
+                    self.run_id.as_deref(),
+                    None,
+                );
+            }
+            Err(e) => log::error!(
+                widget = "WorkflowWidget";
+                "Failed to export workflow report to {}: {}", path.display(), e
+            ),
+        }
+    }
+
+    pub fn is_displaying_history_event(&self) -> bool {
+        let workflow = self.workflow.read().unwrap();
+        workflow.history.is_displaying_event()
+    }
+
+    pub fn is_split_history_detail(&self) -> bool {
+        let workflow = self.workflow.read().unwrap();
+        workflow.history.is_split_detail()
+    }
+
+    /// Whether the history is shown with nothing over it, when all of its keybinds are available.
+    /// An event's detail may be beside it.
+    fn is_browsing_history(&self) -> bool {
+        let workflow = self.workflow.read().unwrap();
+        self.input().is_none()
+            && workflow.stack_trace.is_none()
+            && !workflow.show_raw_describe
+            && workflow.history.search.is_none()
+            && !workflow.history.is_displaying_event()
+    }
+
+    /// A view of the workflow execution the displayed event links to, like a child workflow or
+    /// the run that continued this one, if any.
+    fn open_linked_execution(&self) -> Option<ViewWidget> {
+        let (workflow_id, run_id) = {
+            let workflow = self.workflow.read().unwrap();
+            let event = workflow
+                .history
+                .display_event
+                .and_then(|i| workflow.history.events.get(i))?;
+            let (workflow_id, run_id) = event.linked_execution(&self.workflow_id)?;
+            (workflow_id.to_string(), run_id.map(str::to_string))
+        };
+        // Stop polling heartbeats while this workflow is kept to go back to
+        self.workflow.write().unwrap().watched_activity = None;
+
+        Some(ViewWidget::Workflow(WorkflowWidget::new(
+            &self.temporal_client,
+            &self.settings,
+            &workflow_id,
+            run_id.as_deref(),
+            self.theme,
+        )))
+    }
+}
+
+impl widgets::Widget for &WorkflowWidget {
+    fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let workflow = self.workflow.read().unwrap();
+        let error = self.error();
 
         if workflow.execution.is_none() {
+            if let Some(error) = error {
+                render_error_banner(&error, self.theme, area, buf);
+            }
             return;
         }
 
-        let workflow_execution = workflow.execution.as_ref().unwrap();
+        let workflow_execution = workflow.execution.as_ref().unwrap();
+
+        let failing_workflow_task =
+            workflow
+                .history
+                .failing_workflow_task()
+                .and_then(|(event, attrs)| {
+                    attrs.failure.as_ref().map(|failure| {
+                        let cause = enums::WorkflowTaskFailedCause::try_from(attrs.cause)
+                            .unwrap_or(enums::WorkflowTaskFailedCause::Unspecified);
+                        FailureWidget::from(failure).titled(&format!(
+                            "Workflow task failing: {} (event {})",
+                            cause.as_str_name(),
+                            event.id
+                        ))
+                    })
+                });
+
+        let reset_points_height = if workflow.show_reset_points {
+            (workflow_execution.reset_points.len() as u16 + 2).min(8)
+        } else {
+            0
+        };
+
+        // IDs get full width rows and wrap instead of being clipped, as users copy them from here,
+        // unless configured to elide their middle.
+        let inner_width = area.width.saturating_sub(2).max(1);
+        let id_lines = [
+            ("Workflow ID: ", self.workflow_id.as_str()),
+            ("Run ID: ", workflow_execution.run_id.as_str()),
+        ]
+        .map(|(key, value)| {
+            let value = if self.settings.middle_truncate_ids {
+                truncate_middle(value, (inner_width as usize).saturating_sub(key.len()))
+            } else {
+                value.to_owned()
+            };
+            text::Line::from(vec![
+                key.fg(self.theme.header_foreground),
+                value.fg(self.theme.foreground),
+            ])
+        });
+        let ids_height: u16 = id_lines
+            .iter()
+            .map(|line| (line.width() as u16).div_ceil(inner_width).max(1))
+            .sum();
+
+        // Only populated fields are shown, and the header grows with them unless collapsed.
+        let header_fields: Vec<(&str, String)> = if workflow.collapse_header {
+            Vec::new()
+        } else {
+            [
+                ("Start", workflow_execution.start_time_as_string()),
+                ("End", workflow_execution.close_time_as_string()),
+                (
+                    "Duration",
+                    workflow_execution.execution_duration_as_string(),
+                ),
+                ("Workflow Type", workflow_execution.r#type.clone()),
+                ("Task Queue", workflow_execution.task_queue.clone()),
+                (
+                    "Task Queue Kind",
+                    workflow
+                        .history
+                        .task_queue_kind()
+                        .map(|kind| kind.as_str_name().to_string())
+                        .unwrap_or_default(),
+                ),
+                (
+                    "History Size (Bytes)",
+                    format!("{}", workflow_execution.history_size_bytes),
+                ),
+                (
+                    "Retention Remaining",
+                    self.settings
+                        .retention
+                        .map(|retention| {
+                            workflow_execution.retention_remaining_as_string(retention)
+                        })
+                        .unwrap_or_default(),
+                ),
+            ]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty() && value != "-")
+            .collect()
+        };
+
+        let watched_activity = workflow.watched_activity.as_ref().and_then(|id| {
+            workflow
+                .pending_activities
+                .iter()
+                .find(|activity| &activity.id == id)
+        });
+
+        let vertical = &layout::Layout::vertical([
+            layout::Constraint::Length(2 + ids_height + header_fields.len() as u16),
+            layout::Constraint::Length(reset_points_height),
+            layout::Constraint::Length(if failing_workflow_task.is_some() {
+                10
+            } else {
+                0
+            }),
+            layout::Constraint::Length(if watched_activity.is_some() { 10 } else { 0 }),
+            layout::Constraint::Length(match workflow.pending_nexus_operations.len() {
+                0 => 0,
+                n => (n as u16 + 3).min(8),
+            }),
+            layout::Constraint::Length(if !workflow.show_pending_activities {
+                0
+            } else if workflow
+                .pending_activities
+                .iter()
+                .any(|activity| activity.heartbeat_details.is_some())
+            {
+                (workflow.pending_activities.len() as u16 + 3).clamp(8, 12)
+            } else {
+                (workflow.pending_activities.len() as u16 + 3).min(12)
+            }),
+            layout::Constraint::Length(if workflow.show_attributes {
+                (workflow_execution.memo.len() as u16
+                    + workflow_execution.search_attributes.len() as u16
+                    + 3)
+                .min(12)
+            } else {
+                0
+            }),
+            layout::Constraint::Fill(1),
+            layout::Constraint::Length(
+                if workflow.history.has_more_pages()
+                    || workflow.pending_restart.is_some()
+                    || workflow.current_run_id.is_some()
+                {
+                    1
+                } else {
+                    0
+                },
+            ),
+        ]);
+        let [header_area, reset_points_area, failure_area, heartbeats_area, nexus_area, pending_activities_area, attributes_area, body_area, progress_area] =
+            vertical.areas(area);
+
+        let body_area = match self.input() {
+            Some(input) => {
+                let [input_area, rest_area] = layout::Layout::vertical([
+                    layout::Constraint::Length(3),
+                    layout::Constraint::Fill(1),
+                ])
+                .areas(body_area);
+                widgets::Widget::render(input, input_area, buf);
+                rest_area
+            }
+            None => body_area,
+        };
+
+        let body_area = match error {
+            Some(error) => {
+                let [error_area, rest_area] = layout::Layout::vertical([
+                    layout::Constraint::Length(1),
+                    layout::Constraint::Fill(1),
+                ])
+                .areas(body_area);
+                render_error_banner(&error, self.theme, error_area, buf);
+                rest_area
+            }
+            None => body_area,
+        };
+
+        if let Some(activity) = watched_activity {
+            activity.render(heartbeats_area, buf);
+        }
+
+        if !workflow.pending_nexus_operations.is_empty() {
+            let nexus_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Pending Nexus operations".fg(self.theme.header_foreground))
+                .border_style(style::Style::new().fg(self.theme.border));
+
+            let rows = workflow
+                .pending_nexus_operations
+                .iter()
+                .map(|operation| {
+                    let next_attempt = match operation.next_attempt_schedule_time {
+                        Some(dt) => format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")),
+                        None => operation
+                            .scheduled_time
+                            .map(|dt| format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")))
+                            .unwrap_or("-".to_string()),
+                    };
+                    widgets::Row::new(vec![
+                        widgets::Cell::new(format!(
+                            "{}/{}",
+                            operation.service, operation.operation
+                        )),
+                        widgets::Cell::new(operation.endpoint.clone()),
+                        widgets::Cell::new(operation.state_as_str()),
+                        widgets::Cell::new(operation.attempt.to_string()),
+                        widgets::Cell::new(next_attempt),
+                        widgets::Cell::new(operation.problem.clone().unwrap_or_default()),
+                    ])
+                })
+                .collect::<Vec<widgets::Row>>();
+
+            let header = [
+                "Operation",
+                "Endpoint",
+                "State",
+                "Attempt",
+                "Scheduled",
+                "Problem",
+            ]
+            .into_iter()
+            .map(widgets::Cell::from)
+            .collect::<widgets::Row>()
+            .style(
+                style::Style::new()
+                    .fg(self.theme.header_foreground)
+                    .bg(self.theme.header_background),
+            );
+
+            let nexus_table = widgets::Table::new(
+                rows,
+                [
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Length(12),
+                    layout::Constraint::Length(8),
+                    layout::Constraint::Length(24),
+                    layout::Constraint::Fill(2),
+                ],
+            )
+            .header(header)
+            .block(nexus_block)
+            .fg(self.theme.foreground)
+            .bg(self.theme.background);
+
+            widgets::Widget::render(nexus_table, nexus_area, buf);
+        }
+
+        if workflow.show_pending_activities {
+            let pending_activities_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title(
+                    format!("Pending activities ({})", workflow.pending_activities.len())
+                        .fg(self.theme.header_foreground),
+                )
+                .border_style(style::Style::new().fg(self.theme.border));
+
+            let heartbeat_details: Vec<PayloadWidget> = workflow
+                .pending_activities
+                .iter()
+                .filter_map(|activity| {
+                    let mut details = activity.heartbeat_details.as_ref()?.first()?.clone();
+                    details.title = format!("Heartbeat details: {}", activity.id);
+                    Some(details)
+                })
+                .collect();
+            let (table_area, details_area) = if heartbeat_details.is_empty() {
+                (pending_activities_area, None)
+            } else {
+                let [table_area, details_area] = layout::Layout::horizontal([
+                    layout::Constraint::Fill(3),
+                    layout::Constraint::Fill(2),
+                ])
+                .areas(pending_activities_area);
+                (table_area, Some(details_area))
+            };
+
+            let rows = workflow
+                .pending_activities
+                .iter()
+                .map(|activity| {
+                    widgets::Row::new(vec![
+                        widgets::Cell::new(activity.id.clone()),
+                        widgets::Cell::new(activity.r#type.clone().unwrap_or("-".to_string())),
+                        widgets::Cell::new(activity.state_as_str()),
+                        widgets::Cell::new(format!(
+                            "{}/{}",
+                            activity.attempt, activity.maximum_attempts
+                        )),
+                        widgets::Cell::new(
+                            activity
+                                .last_failure
+                                .as_ref()
+                                .map(|failure| failure.message.clone())
+                                .unwrap_or_default(),
+                        ),
+                    ])
+                })
+                .collect::<Vec<widgets::Row>>();
+
+            let header = ["ID", "Type", "State", "Attempt", "Last failure"]
+                .into_iter()
+                .map(widgets::Cell::from)
+                .collect::<widgets::Row>()
+                .style(
+                    style::Style::new()
+                        .fg(self.theme.header_foreground)
+                        .bg(self.theme.header_background),
+                );
+
+            let pending_activities_table = widgets::Table::new(
+                rows,
+                [
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Length(16),
+                    layout::Constraint::Length(8),
+                    layout::Constraint::Fill(2),
+                ],
+            )
+            .header(header)
+            .block(pending_activities_block)
+            .fg(self.theme.foreground)
+            .bg(self.theme.background);
+
+            widgets::Widget::render(pending_activities_table, table_area, buf);
+
+            if let Some(details_area) = details_area {
+                let details_areas = layout::Layout::vertical(
+                    heartbeat_details
+                        .iter()
+                        .map(|_| layout::Constraint::Fill(1)),
+                )
+                .split(details_area);
+                for (details, area) in heartbeat_details.iter().zip(details_areas.iter()) {
+                    details.render(*area, buf);
+                }
+            }
+        }
+
+        if workflow.show_attributes {
+            let attributes_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Memo and search attributes".fg(self.theme.header_foreground))
+                .title_bottom(
+                    text::Line::from("Only search attributes can be queried").right_aligned(),
+                )
+                .border_style(style::Style::new().fg(self.theme.border));
+
+            // Search attributes first, marked as queryable, then memo fields
+            let rows = workflow_execution
+                .search_attributes
+                .iter()
+                .map(|(name, value)| (name, value, true))
+                .chain(
+                    workflow_execution
+                        .memo
+                        .iter()
+                        .map(|(name, value)| (name, value, false)),
+                )
+                .map(|(name, value, is_queryable)| {
+                    let value = match value {
+                        serde_json::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    widgets::Row::new(vec![
+                        if is_queryable {
+                            widgets::Cell::new(name.clone().fg(self.theme.header_foreground).bold())
+                        } else {
+                            widgets::Cell::new(name.clone())
+                        },
+                        widgets::Cell::new(if is_queryable {
+                            "Search attribute"
+                        } else {
+                            "Memo"
+                        }),
+                        widgets::Cell::new(value),
+                    ])
+                })
+                .collect::<Vec<widgets::Row>>();
+
+            let header = ["Name", "Kind", "Value"]
+                .into_iter()
+                .map(widgets::Cell::from)
+                .collect::<widgets::Row>()
+                .style(
+                    style::Style::new()
+                        .fg(self.theme.header_foreground)
+                        .bg(self.theme.header_background),
+                );
+
+            let attributes_table = widgets::Table::new(
+                rows,
+                [
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Length(16),
+                    layout::Constraint::Fill(2),
+                ],
+            )
+            .header(header)
+            .block(attributes_block)
+            .fg(self.theme.foreground)
+            .bg(self.theme.background);
+
+            widgets::Widget::render(attributes_table, attributes_area, buf);
+        }
+
+        if workflow.show_reset_points {
+            let reset_points_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Reset points".fg(self.theme.header_foreground))
+                .border_style(style::Style::new().fg(self.theme.border));
+
+            let rows = workflow_execution
+                .reset_points
+                .iter()
+                .map(|reset_point| {
+                    widgets::Row::new(vec![
+                        widgets::Cell::new(
+                            reset_point.first_workflow_task_completed_id.to_string(),
+                        ),
+                        widgets::Cell::new(reset_point.build_id_or_checksum().to_string()),
+                        widgets::Cell::new(
+                            reset_point
+                                .create_time
+                                .map(|dt| format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")))
+                                .unwrap_or("-".to_string()),
+                        ),
+                        widgets::Cell::new(
+                            reset_point
+                                .expire_time
+                                .map(|dt| format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z")))
+                                .unwrap_or("-".to_string()),
+                        ),
+                        widgets::Cell::new(if reset_point.resettable {
+                            "Resettable"
+                        } else {
+                            "Not resettable"
+                        }),
+                    ])
+                })
+                .collect::<Vec<widgets::Row>>();
+
+            let header = ["Event ID", "Build ID", "Created", "Expires", ""]
+                .into_iter()
+                .map(widgets::Cell::from)
+                .collect::<widgets::Row>()
+                .style(
+                    style::Style::new()
+                        .fg(self.theme.header_foreground)
+                        .bg(self.theme.header_background),
+                );
+
+            let reset_points_table = widgets::Table::new(
+                rows,
+                [
+                    layout::Constraint::Length(10),
+                    layout::Constraint::Fill(1),
+                    layout::Constraint::Length(24),
+                    layout::Constraint::Length(24),
+                    layout::Constraint::Length(16),
+                ],
+            )
+            .header(header)
+            .block(reset_points_block)
+            .fg(self.theme.foreground)
+            .bg(self.theme.background);
+
+            widgets::Widget::render(reset_points_table, reset_points_area, buf);
+        }
+
+        if let Some(failure) = failing_workflow_task.as_ref() {
+            failure.render(failure_area, buf);
+        }
 
         let (status, status_color) = (
             workflow_execution.status_as_string(),
@@ -1377,99 +4339,253 @@ impl widgets::Widget for &WorkflowWidget {
             .title(text::Span::from(
                 self.workflow_id.clone().fg(self.theme.header_foreground),
             ))
+            .title(
+                text::Line::from(match workflow.execution_source {
+                    _ if self
+                        .copied_at
+                        .is_some_and(|copied_at| copied_at.elapsed() < COPIED_NOTICE_DURATION) =>
+                    {
+                        "Copied!"
+                    }
+                    ExecutionSource::List => "From list, press d to describe",
+                    ExecutionSource::Describe => "",
+                })
+                .right_aligned()
+                .fg(self.theme.header_foreground),
+            )
             .border_style(style::Style::new().fg(self.theme.border));
 
         let inner_header_area = header_block.inner(header_area);
 
         widgets::Widget::render(header_block, header_area, buf);
 
+        let header_vertical = &layout::Layout::vertical([
+            layout::Constraint::Length(ids_height),
+            layout::Constraint::Fill(1),
+        ]);
+        let [ids_area, header_values_area] = header_vertical.areas(inner_header_area);
+
+        widgets::Paragraph::new(id_lines.to_vec())
+            .wrap(widgets::Wrap { trim: false })
+            .bg(self.theme.background)
+            .render(ids_area, buf);
+
         let header_horizontal =
             &layout::Layout::horizontal([layout::Constraint::Fill(1), layout::Constraint::Fill(1)]);
-        let [header_left_area, header_right_area] = header_horizontal.areas(inner_header_area);
-
-        let left_keys = widgets::Paragraph::new(vec![
-            text::Line::raw("Start").left_aligned(),
-            text::Line::raw("End").left_aligned(),
-            text::Line::raw("Duration").left_aligned(),
-            text::Line::raw("Run ID").left_aligned(),
-            text::Line::raw("Workflow Type").left_aligned(),
-            text::Line::raw("Task Queue").left_aligned(),
-            text::Line::raw("History Size (Bytes)").left_aligned(),
-        ])
+        let [header_left_area, header_right_area] = header_horizontal.areas(header_values_area);
+
+        let left_keys = widgets::Paragraph::new(
+            header_fields
+                .iter()
+                .map(|(key, _)| text::Line::raw(*key).left_aligned())
+                .collect::<Vec<text::Line>>(),
+        )
         .fg(self.theme.foreground)
         .bg(self.theme.background);
 
-        let [start_time, end_time, execution_duration, workflow_run_id, workflow_type, task_queue, history_size_bytes] = [
-            workflow_execution.start_time_as_string(),
-            workflow_execution.close_time_as_string(),
-            workflow_execution.execution_duration_as_string(),
-            workflow_execution.run_id.clone(),
-            workflow_execution.r#type.clone(),
-            workflow_execution.task_queue.clone(),
-            format!("{}", workflow_execution.history_size_bytes),
-        ];
-
-        let right_values = widgets::Paragraph::new(vec![
-            text::Line::raw(start_time).right_aligned(),
-            text::Line::raw(end_time).right_aligned(),
-            text::Line::raw(execution_duration).right_aligned(),
-            text::Line::raw(workflow_run_id).right_aligned(),
-            text::Line::raw(workflow_type).right_aligned(),
-            text::Line::raw(task_queue).right_aligned(),
-            text::Line::raw(history_size_bytes).right_aligned(),
-        ])
+        let right_values = widgets::Paragraph::new(
+            header_fields
+                .iter()
+                .map(|(_, value)| text::Line::raw(value.as_str()).right_aligned())
+                .collect::<Vec<text::Line>>(),
+        )
         .fg(self.theme.foreground)
         .bg(self.theme.background);
 
         widgets::Widget::render(left_keys, header_left_area, buf);
         widgets::Widget::render(right_values, header_right_area, buf);
 
+        if let Some(restart) = workflow.pending_restart.as_ref() {
+            widgets::Paragraph::new(format!(
+                "Reset to event {} to restart activity {}? Press R again to confirm",
+                restart.reset_event_id, restart.activity_id
+            ))
+            .fg(self.theme.foreground)
+            .bg(self.theme.failure_background)
+            .render(progress_area, buf);
+        } else if let Some(current_run_id) = workflow.current_run_id.as_ref() {
+            widgets::Paragraph::new(format!(
+                "Not the current run, which is {}. Press O to open it",
+                current_run_id
+            ))
+            .fg(self.theme.warning_foreground)
+            .bg(self.theme.warning_background)
+            .render(progress_area, buf);
+        } else if workflow.history.has_more_pages() {
+            let loaded = workflow.history.len() as u64;
+            let total = workflow_execution.history_length;
+
+            if total >= loaded && total > 0 {
+                widgets::LineGauge::default()
+                    .ratio(loaded as f64 / total as f64)
+                    .label(format!("Loaded {}/{} events", loaded, total))
+                    .filled_style(style::Style::new().fg(self.theme.running_background))
+                    .unfilled_style(style::Style::new().fg(self.theme.alt_background))
+                    .fg(self.theme.foreground)
+                    .render(progress_area, buf);
+            } else {
+                widgets::Paragraph::new(format!(
+                    "Loaded {}/unknown events, more pages available",
+                    loaded
+                ))
+                .fg(self.theme.foreground)
+                .render(progress_area, buf);
+            }
+        }
+
+        // Rows within the history table's borders
+        *workflow.history_page_height.write().unwrap() =
+            body_area.height.saturating_sub(2) as usize;
         let mut history_state = workflow.history_state.write().unwrap();
         workflow.history.render(body_area, buf, &mut history_state);
+
+        if let Some(stack_trace) = workflow.stack_trace.as_ref() {
+            let stack_trace_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .border_style(style::Style::new().fg(self.theme.border))
+                .title("Stack trace".fg(self.theme.header_foreground))
+                .title_bottom(text::Line::from("Esc to close").right_aligned())
+                .bg(self.theme.background);
+
+            widgets::Clear.render(body_area, buf);
+            widgets::Paragraph::new(stack_trace.as_str())
+                .fg(self.theme.foreground)
+                .scroll((workflow.stack_trace_scroll, 0))
+                .block(stack_trace_block)
+                .render(body_area, buf);
+        } else if workflow.show_raw_describe {
+            let raw_describe_block = widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .border_style(style::Style::new().fg(self.theme.border))
+                .title("Describe response".fg(self.theme.header_foreground))
+                .title_bottom(text::Line::from("Esc to close").right_aligned())
+                .bg(self.theme.background);
+
+            widgets::Clear.render(body_area, buf);
+            widgets::Paragraph::new(
+                workflow
+                    .raw_describe
+                    .as_deref()
+                    .unwrap_or("Not described yet, press d to describe"),
+            )
+            .fg(self.theme.foreground)
+            .scroll((workflow.raw_describe_scroll, 0))
+            .block(raw_describe_block)
+            .render(body_area, buf);
+        }
     }
 }
 
 impl Keybindable for WorkflowWidget {
-    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<ViewWidget> {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        if key.code != event::KeyCode::Char('R') {
+            // Any other key cancels a restart waiting to be confirmed
+            self.workflow.write().unwrap().pending_restart = None;
+        }
+
+        if self.cancel_reason.is_some() {
+            self.handle_cancel_reason_key(key).await;
+            return None;
+        }
+
+        if self.signal.is_some() {
+            self.handle_signal_key(key).await;
+            return None;
+        }
+
+        if self.workflow.read().unwrap().stack_trace.is_some() {
+            self.handle_stack_trace_key(key);
+            return None;
+        }
+
+        if self.workflow.read().unwrap().show_raw_describe {
+            self.handle_raw_describe_key(key);
+            return None;
+        }
+
+        if self.search.is_some() {
+            self.handle_search_key(key).await;
+            return None;
+        }
+
+        if key.code == event::KeyCode::Esc && self.stop_loading_every_page() {
+            return None;
+        }
+
+        let is_searching = self.workflow.read().unwrap().history.search.is_some();
+        if key.code == event::KeyCode::Esc && is_searching && !self.is_displaying_history_event() {
+            // Clear the search before leaving the view
+            self.workflow.write().unwrap().history.search = None;
+            return None;
+        }
+
+        if key.code == event::KeyCode::Esc && self.error().is_some() {
+            // Dismiss the error banner before leaving the view
+            self.set_loading_state(LoadingState::Idle);
+            return None;
+        }
+
+        let pending_key = self.pending_key.take();
         match key {
-            event::KeyEvent {
-                code: event::KeyCode::Char('j'),
-                ..
-            }
-            | event::KeyEvent {
-                code: event::KeyCode::Down,
-                ..
-            } => {
+            key if self.settings.keybinds.matches(Action::NextRow, &key) => {
                 let is_displaying_history_event = self.is_displaying_history_event();
-                if !is_displaying_history_event {
+                if is_displaying_history_event {
+                    let mut workflow = self.workflow.write().unwrap();
+                    workflow.history.move_payload_field(true);
+                } else {
                     self.next_row().await
                 }
             }
+            key if self.settings.keybinds.matches(Action::PreviousRow, &key) => {
+                let is_displaying_history_event = self.is_displaying_history_event();
+                if is_displaying_history_event {
+                    let mut workflow = self.workflow.write().unwrap();
+                    workflow.history.move_payload_field(false);
+                } else {
+                    self.previous_row()
+                }
+            }
             event::KeyEvent {
-                code: event::KeyCode::Char('k'),
+                code: event::KeyCode::PageDown,
                 ..
             }
             | event::KeyEvent {
-                code: event::KeyCode::Up,
+                code: event::KeyCode::Char('d'),
+                modifiers: event::KeyModifiers::CONTROL,
                 ..
-            } => {
-                let is_displaying_history_event = self.is_displaying_history_event();
-                if !is_displaying_history_event {
-                    self.previous_row()
-                }
-            }
-            // Reload history table
+            } if !self.is_displaying_history_event() => self.next_page().await,
             event::KeyEvent {
-                code: event::KeyCode::Char('r'),
+                code: event::KeyCode::PageUp,
+                ..
+            }
+            | event::KeyEvent {
+                code: event::KeyCode::Char('u'),
                 modifiers: event::KeyModifiers::CONTROL,
                 ..
-            } => self.reload().await,
+            } if !self.is_displaying_history_event() => self.previous_page(),
+            // Jump to the first event with gg
+            event::KeyEvent {
+                code: event::KeyCode::Char('g'),
+                modifiers: event::KeyModifiers::NONE,
+                ..
+            } if !self.is_displaying_history_event() => match pending_key {
+                Some('g') => self.first_row(),
+                _ => self.pending_key = Some('g'),
+            },
+            event::KeyEvent {
+                code: event::KeyCode::Char('G'),
+                ..
+            } if !self.is_displaying_history_event() => self.last_row().await,
+            // Reload history table
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
             event::KeyEvent {
                 code: event::KeyCode::Enter,
                 ..
             } => {
                 let is_displaying_history_event = self.is_displaying_history_event();
-                if is_displaying_history_event {
+                if let Some(workflow) = self.open_linked_execution() {
+                    return Some(Navigation::Push(workflow));
+                } else if is_displaying_history_event {
                     let mut workflow = self.workflow.write().unwrap();
                     workflow.history.clear_display_event();
                 } else {
@@ -1482,14 +4598,311 @@ impl Keybindable for WorkflowWidget {
                     }
                 }
             }
+            // Navigate into and out of the displayed event's payload
             event::KeyEvent {
-                code: event::KeyCode::Esc,
+                code: event::KeyCode::Right,
                 ..
             } => {
-                return Some(ViewWidget::WorkflowTable(WorkflowTableWidget::new(
-                    &self.temporal_client,
-                    self.theme,
-                    48,
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.enter_payload_field();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Left,
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.leave_payload_field();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Tab,
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.next_payload();
+            }
+            // Open the workflow execution the displayed event links to
+            event::KeyEvent {
+                code: event::KeyCode::Char('o'),
+                ..
+            } => {
+                if let Some(workflow) = self.open_linked_execution() {
+                    return Some(Navigation::Push(workflow));
+                }
+            }
+            // Copy the selected payload value, or the workflow's IDs outside of events
+            event::KeyEvent {
+                code: event::KeyCode::Char('y'),
+                ..
+            } => {
+                if self.is_displaying_history_event() {
+                    self.copy_payload_value()
+                } else {
+                    self.copy_workflow()
+                }
+            }
+            // Hidden debugging keybind, dumps raw responses to the log file
+            event::KeyEvent {
+                code: event::KeyCode::Char('l'),
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } if self.settings.debug => self.log_raw_responses(),
+            event::KeyEvent {
+                code: event::KeyCode::Char('d'),
+                modifiers: event::KeyModifiers::NONE,
+                ..
+            } => self.describe().await,
+            // Display the full describe response
+            event::KeyEvent {
+                code: event::KeyCode::Char('D'),
+                ..
+            } => self.toggle_raw_describe(),
+            event::KeyEvent {
+                code: event::KeyCode::Char('p'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.show_reset_points = !workflow.show_reset_points;
+            }
+            // List the pending activities
+            event::KeyEvent {
+                code: event::KeyCode::Char('a'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.show_pending_activities = !workflow.show_pending_activities;
+            }
+            // List the memo and search attributes
+            event::KeyEvent {
+                code: event::KeyCode::Char('M'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.show_attributes = !workflow.show_attributes;
+            }
+            // Watch the heartbeats of the next pending activity
+            event::KeyEvent {
+                code: event::KeyCode::Char('w'),
+                modifiers: event::KeyModifiers::NONE,
+                ..
+            } => self.watch_next_activity(),
+            // Describe the workers polling the workflow's task queue
+            event::KeyEvent {
+                code: event::KeyCode::Char('W'),
+                ..
+            } => {
+                let task_queue = {
+                    let workflow = self.workflow.read().unwrap();
+                    workflow
+                        .execution
+                        .as_ref()
+                        .map(|execution| (execution.task_queue.clone(), execution.run_id.clone()))
+                };
+                if let Some((task_queue, run_id)) = task_queue {
+                    self.workflow.write().unwrap().watched_activity = None;
+                    return Some(Navigation::Push(ViewWidget::TaskQueue(
+                        TaskQueueWidget::new(
+                            &self.temporal_client,
+                            &self.settings,
+                            &task_queue,
+                            self.theme,
+                        )
+                        .with_workflow(&self.workflow_id, Some(&run_id)),
+                    )));
+                }
+            }
+            // Open the workflow's current run, when displaying an older one
+            event::KeyEvent {
+                code: event::KeyCode::Char('O'),
+                ..
+            } => {
+                let current_run_id = self.workflow.read().unwrap().current_run_id.clone();
+                if let Some(run_id) = current_run_id {
+                    self.workflow.write().unwrap().watched_activity = None;
+                    return Some(Navigation::Push(ViewWidget::Workflow(WorkflowWidget::new(
+                        &self.temporal_client,
+                        &self.settings,
+                        &self.workflow_id,
+                        Some(&run_id),
+                        self.theme,
+                    ))));
+                }
+            }
+            // Ask for a reason and cancel the workflow
+            event::KeyEvent {
+                code: event::KeyCode::Char('x'),
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } => self.ask_cancel_reason(),
+            // Ask for a signal to send to the workflow
+            event::KeyEvent {
+                code: event::KeyCode::Char('S'),
+                ..
+            } => self.ask_signal(),
+            // List the children of this workflow
+            event::KeyEvent {
+                code: event::KeyCode::Char('C'),
+                ..
+            } => {
+                self.workflow.write().unwrap().watched_activity = None;
+                return Some(Navigation::Push(ViewWidget::WorkflowTable(
+                    WorkflowTableWidget::new(&self.temporal_client, &self.settings, self.theme)
+                        .with_parent_workflow_id(&self.workflow_id),
+                )));
+            }
+            // Restart the selected activity by resetting the workflow to before it
+            event::KeyEvent {
+                code: event::KeyCode::Char('R'),
+                ..
+            } => return self.restart_activity().await.map(Navigation::Push),
+            // Export a Markdown report of the workflow
+            event::KeyEvent {
+                code: event::KeyCode::Char('m'),
+                ..
+            } => self.export_report(),
+            // Only show events caused by us
+            event::KeyEvent {
+                code: event::KeyCode::Char('i'),
+                ..
+            } => self.toggle_identity_filter(Some(self.settings.identity.clone())),
+            // Only show activity, timer or signal events
+            event::KeyEvent {
+                code: event::KeyCode::Char('e'),
+                ..
+            } if !self.is_displaying_history_event() => self.cycle_event_category(),
+            // Search event types
+            event::KeyEvent {
+                code: event::KeyCode::Char('/'),
+                ..
+            } if !self.is_displaying_history_event() => self.ask_search(),
+            // Cycle through the events matching the search
+            event::KeyEvent {
+                code: event::KeyCode::Char('n'),
+                ..
+            } if is_searching && !self.is_displaying_history_event() => self.next_match(true),
+            event::KeyEvent {
+                code: event::KeyCode::Char('N'),
+                ..
+            } if is_searching && !self.is_displaying_history_event() => self.next_match(false),
+            // Hide internal events like workflow tasks
+            event::KeyEvent {
+                code: event::KeyCode::Char('H'),
+                ..
+            } => self.toggle_hide_noise(),
+            // Only show events caused by the same identity as the selected event
+            event::KeyEvent {
+                code: event::KeyCode::Char('I'),
+                ..
+            } => {
+                let selected = self.get_selected_history_event();
+                let identity = {
+                    let workflow = self.workflow.read().unwrap();
+                    selected
+                        .and_then(|i| workflow.history.events.get(i))
+                        .and_then(|event| event.identity().map(str::to_string))
+                };
+                self.toggle_identity_filter(identity);
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Char('h'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.collapse_header = !workflow.collapse_header;
+            }
+            // Jump to the next failure event
+            event::KeyEvent {
+                code: event::KeyCode::Char('f'),
+                ..
+            } => {
+                let is_displaying_history_event = self.is_displaying_history_event();
+                if !is_displaying_history_event {
+                    self.next_failure_row()
+                }
+            }
+            // Toggle grouping events by workflow task
+            event::KeyEvent {
+                code: event::KeyCode::Char('t'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.toggle_tree();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Char('Q'),
+                ..
+            } => self.request_stack_trace().await,
+            event::KeyEvent {
+                code: event::KeyCode::Char('T'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.toggle_time_format();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Char(' '),
+                ..
+            } => {
+                let history_state_selected = self.get_selected_history_event();
+                let mut workflow = self.workflow.write().unwrap();
+
+                if workflow.history.is_displaying_event() {
+                    // Nothing to fold or mark
+                } else if workflow.history.is_tree() {
+                    if let Some(workflow_task) =
+                        history_state_selected.and_then(|i| workflow.history.toggle_collapsed(i))
+                    {
+                        workflow
+                            .history_state
+                            .write()
+                            .unwrap()
+                            .select(Some(workflow_task));
+                    }
+                } else if let Some(i) = history_state_selected {
+                    workflow.history.toggle_marked(i);
+                }
+            }
+            // Export the marked events
+            event::KeyEvent {
+                code: event::KeyCode::Char('X'),
+                ..
+            } => self.export_marked_events(),
+            // Copy the marked events
+            event::KeyEvent {
+                code: event::KeyCode::Char('Y'),
+                ..
+            } => self.copy_marked_events(),
+            // Export the payloads of the displayed event
+            event::KeyEvent {
+                code: event::KeyCode::Char('x'),
+                ..
+            } => self.export_event_payloads(),
+            // Toggle the split between the history table and the event details
+            event::KeyEvent {
+                code: event::KeyCode::Char('s'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.toggle_split_detail();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Char('<'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.shrink_split();
+            }
+            event::KeyEvent {
+                code: event::KeyCode::Char('>'),
+                ..
+            } => {
+                let mut workflow = self.workflow.write().unwrap();
+                workflow.history.grow_split();
+            }
+            key if self.settings.keybinds.matches(Action::Back, &key) => {
+                // Stop polling heartbeats
+                self.workflow.write().unwrap().watched_activity = None;
+                return Some(Navigation::Back(ViewWidget::WorkflowTable(
+                    WorkflowTableWidget::new(&self.temporal_client, &self.settings, self.theme),
                 )));
             }
             _ => {}
@@ -1499,16 +4912,173 @@ impl Keybindable for WorkflowWidget {
 
     fn keybinds<'k>(&'k self) -> &'k [(&'k str, &'k [&'k str])] {
         let is_displaying_history_event = self.is_displaying_history_event();
-        if is_displaying_history_event {
-            &[("Collapse event", &["Enter"]), ("Previous view", &["Esc"])]
-        } else {
+        if self.cancel_reason.is_some() {
+            &[("Cancel workflow", &["Enter"]), ("Back", &["Esc"])]
+        } else if let Some(signal) = self.signal.as_ref() {
+            match signal.name {
+                None => &[("Enter payload", &["Enter"]), ("Back", &["Esc"])],
+                Some(_) => &[("Send signal", &["Enter"]), ("Back", &["Esc"])],
+            }
+        } else if self.workflow.read().unwrap().stack_trace.is_some() {
+            &[("Scroll", &["j", "k"]), ("Close", &["Esc"])]
+        } else if self.workflow.read().unwrap().show_raw_describe {
             &[
-                ("Up", &["j", "↑"]),
-                ("Down", &["k", "↓"]),
-                ("Expand event", &["Enter"]),
+                ("Scroll", &["j", "k"]),
+                ("Page down/up", &["PgDn", "PgUp"]),
+                ("Close", &["Esc", "D"]),
+            ]
+        } else if self.search.is_some() {
+            &[("Keep search", &["Enter"]), ("Clear search", &["Esc"])]
+        } else if is_displaying_history_event {
+            &[
+                ("Open linked run or collapse", &["Enter"]),
+                ("Select field", &["j", "k"]),
+                ("Into/out of field", &["→", "←"]),
+                ("Next payload", &["Tab"]),
+                ("Copy value", &["y"]),
+                ("Open linked run", &["o"]),
+                ("Export payloads", &["x"]),
                 ("Previous view", &["Esc"]),
-                ("Reload", &["Ctrl+r"]),
             ]
+        } else if self.workflow.read().unwrap().history.search.is_some() {
+            &[
+                ("Up", &["k", "↑"]),
+                ("Down", &["j", "↓"]),
+                ("Next/previous match", &["n", "N"]),
+                ("Search", &["/"]),
+                ("Expand event", &["Enter"]),
+                ("Clear search", &["Esc"]),
+            ]
+        } else if self.is_split_history_detail() {
+            &BROWSING_SPLIT_DETAIL_KEYBINDS
+        } else {
+            &BROWSING_KEYBINDS
+        }
+    }
+
+    fn write_keybinds(&self) -> &[(&str, &[&str])] {
+        if self.is_browsing_history() {
+            &[
+                ("Restart activity", &["R"]),
+                ("Cancel workflow", &["Ctrl+x"]),
+                ("Send signal", &["S"]),
+            ]
+        } else {
+            &[]
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::*;
+
+    #[test]
+    fn pending_activity_keeps_heartbeat_and_started_times_apart() {
+        let info = workflow::PendingActivityInfo {
+            activity_id: "1".to_string(),
+            state: enums::PendingActivityState::Started.into(),
+            last_heartbeat_time: Some((UNIX_EPOCH + Duration::from_secs(200)).into()),
+            last_started_time: Some((UNIX_EPOCH + Duration::from_secs(100)).into()),
+            ..Default::default()
+        };
+
+        let activity = PendingActivity::new(info, Theme::default()).unwrap();
+
+        assert_eq!(
+            activity.last_heartbeat_time,
+            chrono::DateTime::from_timestamp(200, 0)
+        );
+        assert_eq!(
+            activity.last_started_time,
+            chrono::DateTime::from_timestamp(100, 0)
+        );
+    }
+
+    fn payload(data: &str) -> temporal_common::Payload {
+        temporal_common::Payload {
+            data: data.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn payloads(data: &str) -> Option<temporal_common::Payloads> {
+        Some(temporal_common::Payloads {
+            payloads: vec![payload(data)],
+        })
+    }
+
+    fn data(payloads: Vec<&mut temporal_common::Payload>) -> Vec<String> {
+        let mut data: Vec<String> = payloads
+            .into_iter()
+            .map(|payload| String::from_utf8_lossy(&payload.data).into_owned())
+            .collect();
+        data.sort();
+        data
+    }
+
+    #[test]
+    fn event_payloads_include_failure_details_and_causes() {
+        use failure::failure::FailureInfo;
+
+        let cause = failure::Failure {
+            failure_info: Some(FailureInfo::TimeoutFailureInfo(
+                failure::TimeoutFailureInfo {
+                    last_heartbeat_details: payloads("heartbeat"),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        };
+        let mut event = history::HistoryEvent {
+            attributes: Some(
+                history::history_event::Attributes::ActivityTaskFailedEventAttributes(
+                    history::ActivityTaskFailedEventAttributes {
+                        failure: Some(failure::Failure {
+                            encoded_attributes: Some(payload("attributes")),
+                            failure_info: Some(FailureInfo::ApplicationFailureInfo(
+                                failure::ApplicationFailureInfo {
+                                    details: payloads("details"),
+                                    ..Default::default()
+                                },
+                            )),
+                            cause: Some(Box::new(cause)),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                ),
+            ),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            data(event_payloads_mut(&mut event)),
+            vec!["attributes", "details", "heartbeat"]
+        );
+    }
+
+    #[test]
+    fn describe_payloads_include_memo_and_heartbeat_details() {
+        let mut response = service::DescribeWorkflowExecutionResponse {
+            workflow_execution_info: Some(workflow::WorkflowExecutionInfo {
+                memo: Some(temporal_common::Memo {
+                    fields: collections::HashMap::from([("key".to_string(), payload("memo"))]),
+                }),
+                ..Default::default()
+            }),
+            pending_activities: vec![workflow::PendingActivityInfo {
+                heartbeat_details: payloads("heartbeat"),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            data(describe_payloads_mut(&mut response)),
+            vec!["heartbeat", "memo"]
+        );
+    }
+}
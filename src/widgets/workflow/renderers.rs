@@ -0,0 +1,1335 @@
+//! Renderers of the attributes of each type of history event, looked up by their event type so
+//! that supporting a new type of event only takes adding a renderer here.
+use std::collections;
+use std::sync;
+
+use ratatui::{buffer, layout, prelude::Widget, style, style::Stylize, text, widgets};
+use temporal_sdk_core_protos::temporal::api::{
+    common::v1 as common, enums::v1 as enums, history::v1 as history,
+};
+
+use super::{
+    retry_state_as_str, task_queue_kind_as_str, EventWidget, FailureWidget, PayloadWidget,
+};
+use crate::widgets::common::{humanize_optional_duration, to_pretty_json};
+
+use enums::EventType;
+use history::history_event::Attributes;
+
+/// Renders the attributes of an event in `area`.
+type RenderAttributes = fn(&EventWidget, &Attributes, layout::Rect, &mut buffer::Buffer);
+
+static RENDERERS: sync::OnceLock<collections::HashMap<EventType, RenderAttributes>> =
+    sync::OnceLock::new();
+
+fn renderers() -> &'static collections::HashMap<EventType, RenderAttributes> {
+    RENDERERS.get_or_init(|| {
+        let mut renderers: collections::HashMap<EventType, RenderAttributes> =
+            collections::HashMap::new();
+        renderers.insert(
+            EventType::WorkflowExecutionStarted,
+            workflow_execution_started,
+        );
+        renderers.insert(EventType::WorkflowTaskScheduled, workflow_task_scheduled);
+        renderers.insert(EventType::WorkflowTaskStarted, workflow_task_started);
+        renderers.insert(EventType::WorkflowTaskCompleted, workflow_task_completed);
+        renderers.insert(EventType::WorkflowTaskFailed, workflow_task_failed);
+        renderers.insert(EventType::WorkflowTaskTimedOut, workflow_task_timed_out);
+        renderers.insert(
+            EventType::WorkflowExecutionCompleted,
+            workflow_execution_completed,
+        );
+        renderers.insert(
+            EventType::WorkflowExecutionTimedOut,
+            workflow_execution_timed_out,
+        );
+        renderers.insert(
+            EventType::WorkflowExecutionCancelRequested,
+            workflow_execution_cancel_requested,
+        );
+        renderers.insert(
+            EventType::WorkflowExecutionCanceled,
+            workflow_execution_canceled,
+        );
+        renderers.insert(
+            EventType::WorkflowExecutionFailed,
+            workflow_execution_failed,
+        );
+        renderers.insert(EventType::ActivityTaskScheduled, activity_task_scheduled);
+        renderers.insert(EventType::ActivityTaskStarted, activity_task_started);
+        renderers.insert(EventType::ActivityTaskCompleted, activity_task_completed);
+        renderers.insert(
+            EventType::ActivityTaskCancelRequested,
+            activity_task_cancel_requested,
+        );
+        renderers.insert(EventType::ActivityTaskCanceled, activity_task_canceled);
+        renderers.insert(EventType::ActivityTaskFailed, activity_task_failed);
+        renderers.insert(EventType::ActivityTaskTimedOut, activity_task_timed_out);
+        renderers.insert(
+            EventType::WorkflowExecutionSignaled,
+            workflow_execution_signaled,
+        );
+        renderers.insert(EventType::MarkerRecorded, marker_recorded);
+        renderers.insert(EventType::TimerStarted, timer_started);
+        renderers.insert(EventType::TimerFired, timer_fired);
+        renderers.insert(EventType::TimerCanceled, timer_canceled);
+        renderers.insert(
+            EventType::StartChildWorkflowExecutionInitiated,
+            start_child_workflow_execution_initiated,
+        );
+        renderers.insert(
+            EventType::StartChildWorkflowExecutionFailed,
+            start_child_workflow_execution_failed,
+        );
+        renderers.insert(
+            EventType::ChildWorkflowExecutionStarted,
+            child_workflow_execution_started,
+        );
+        renderers.insert(
+            EventType::ChildWorkflowExecutionCompleted,
+            child_workflow_execution_completed,
+        );
+        renderers.insert(
+            EventType::ChildWorkflowExecutionFailed,
+            child_workflow_execution_failed,
+        );
+        renderers
+    })
+}
+
+/// Render the attributes of `event` with the renderer registered for its type, or as raw
+/// attributes if there is none.
+pub(super) fn render(event: &EventWidget, area: layout::Rect, buf: &mut buffer::Buffer) {
+    let Some(attributes) = event.attributes.as_ref() else {
+        return;
+    };
+
+    match renderers().get(&event.r#type) {
+        Some(render_attributes) => render_attributes(event, attributes, area, buf),
+        None => raw(event, attributes, area, buf),
+    }
+}
+
+/// Fallback for events without a renderer, showing all their attributes as JSON.
+fn raw(event: &EventWidget, attributes: &Attributes, area: layout::Rect, buf: &mut buffer::Buffer) {
+    widgets::Paragraph::new(to_pretty_json(attributes))
+        .fg(event.theme.foreground)
+        .wrap(widgets::Wrap { trim: false })
+        .render(area, buf);
+}
+
+fn workflow_execution_started(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionStartedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(9), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Workflow type name: ".into(),
+            text::Span::from(&attrs.workflow_type.as_ref().unwrap().name),
+        ]),
+        text::Line::from(vec![
+            "Task queue name: ".into(),
+            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
+        ]),
+        text::Line::from(vec![
+            "Task queue kind: ".into(),
+            text::Span::from(task_queue_kind_as_str(
+                attrs.task_queue.as_ref().unwrap().kind,
+            )),
+        ]),
+        text::Line::from(vec![
+            "Workflow task timeout: ".into(),
+            text::Span::from(humanize_optional_duration(attrs.workflow_task_timeout)),
+        ]),
+        text::Line::from(vec![
+            "Attempt: ".into(),
+            text::Span::from(format!("{}", attrs.attempt)),
+        ]),
+        text::Line::from(vec![
+            "Original execution run ID: ".into(),
+            text::Span::from(&attrs.original_execution_run_id),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "First execution run ID: ".into(),
+            text::Span::from(&attrs.first_execution_run_id),
+        ]),
+        text::Line::from(vec![
+            "Workflow ID: ".into(),
+            text::Span::from(&attrs.workflow_id),
+        ]),
+    ];
+
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(payloads) = attrs.input.as_ref() {
+        for p in payloads.payloads.iter().take(1) {
+            let payload = event.payload(p, "Input");
+            payload.render(areas[1], buf);
+        }
+    }
+}
+
+fn workflow_task_scheduled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowTaskScheduledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Task queue name: ".into(),
+            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
+        ]),
+        text::Line::from(vec![
+            "Task queue kind: ".into(),
+            text::Span::from(task_queue_kind_as_str(
+                attrs.task_queue.as_ref().unwrap().kind,
+            )),
+        ]),
+        text::Line::from(vec![
+            "Start to close timeout: ".into(),
+            text::Span::from(humanize_optional_duration(attrs.start_to_close_timeout)),
+        ]),
+        text::Line::from(vec![
+            "Attempt: ".into(),
+            text::Span::from(format!("{}", attrs.attempt)),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_task_started(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowTaskStartedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(format!("{}", attrs.scheduled_event_id)),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Request ID: ".into(),
+            text::Span::from(&attrs.request_id),
+        ]),
+        text::Line::from(vec![
+            "History size bytes: ".into(),
+            text::Span::from(format!("{}", &attrs.history_size_bytes)),
+        ]),
+        text::Line::from(vec![
+            "Worker version: ".into(),
+            text::Span::from(match &attrs.worker_version {
+                Some(ts) => &ts.build_id,
+                None => "-",
+            }),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_task_completed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowTaskCompletedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(format!("{}", attrs.scheduled_event_id)),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(format!("{}", attrs.started_event_id)),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Worker version: ".into(),
+            text::Span::from(if let Some(ts) = &attrs.worker_version {
+                &ts.build_id
+            } else {
+                "-"
+            }),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_task_failed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowTaskFailedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(4), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(format!("{}", attrs.scheduled_event_id)),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(format!("{}", attrs.started_event_id)),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Worker version: ".into(),
+            text::Span::from(if let Some(ts) = &attrs.worker_version {
+                &ts.build_id
+            } else {
+                "-"
+            }),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(areas[1], buf);
+    }
+}
+
+fn workflow_task_timed_out(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowTaskTimedOutEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let timeout_type = match attrs.timeout_type {
+        1 => enums::TimeoutType::StartToClose,
+        2 => enums::TimeoutType::ScheduleToStart,
+        3 => enums::TimeoutType::ScheduleToClose,
+        4 => enums::TimeoutType::Heartbeat,
+        _ => enums::TimeoutType::Unspecified,
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(format!("{}", attrs.scheduled_event_id)),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(format!("{}", attrs.started_event_id)),
+        ]),
+        text::Line::from(vec![
+            "Timeout type: ".into(),
+            text::Span::from(timeout_type.as_str_name()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_execution_completed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionCompletedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = vec![text::Line::from(vec![
+        "Workflow task completed event ID: ".into(),
+        text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+    ])];
+    if !attrs.new_execution_run_id.is_empty() {
+        lines.push(text::Line::from(vec![
+            "New execution run ID: ".into(),
+            text::Span::from(&attrs.new_execution_run_id),
+            " (press Enter to open)".fg(event.theme.header_foreground),
+        ]));
+    }
+    let results = attrs
+        .result
+        .as_ref()
+        .map(|payloads| payloads.payloads.as_slice())
+        .unwrap_or_default();
+    if results.is_empty() {
+        lines.push(text::Line::from("No result"));
+    }
+
+    let [lines_area, results_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    // Workflows mostly return a single value, but may return many
+    let results_areas =
+        layout::Layout::vertical(results.iter().map(|_| layout::Constraint::Fill(1)))
+            .split(results_area);
+    for (i, (p, result_area)) in results.iter().zip(results_areas.iter()).enumerate() {
+        let title = match results.len() {
+            1 => "Result".to_string(),
+            _ => format!("Result {}", i + 1),
+        };
+        event.payload(p, &title).render(*result_area, buf);
+    }
+}
+
+fn workflow_execution_timed_out(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionTimedOutEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = vec![text::Line::from(vec![
+        "Retry state: ".into(),
+        text::Span::from(retry_state_as_str(attrs.retry_state)),
+    ])];
+    if !attrs.new_execution_run_id.is_empty() {
+        lines.push(text::Line::from(vec![
+            "New execution run ID: ".into(),
+            text::Span::from(&attrs.new_execution_run_id),
+            " (press Enter to open)".fg(event.theme.header_foreground),
+        ]));
+    }
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_execution_cancel_requested(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionCancelRequestedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec!["Cause: ".into(), text::Span::from(&attrs.cause)]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_execution_canceled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionCanceledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![text::Line::from(vec![
+        "Workflow task completed event ID: ".into(),
+        text::Span::from(format!("{}", attrs.workflow_task_completed_event_id)),
+    ])];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_execution_failed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionFailedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = vec![
+        text::Line::from(vec![
+            "Retry state: ".into(),
+            text::Span::from(retry_state_as_str(attrs.retry_state)),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+    ];
+    if !attrs.new_execution_run_id.is_empty() {
+        lines.push(text::Line::from(vec![
+            "New execution run ID: ".into(),
+            text::Span::from(&attrs.new_execution_run_id),
+            " (press Enter to open)".fg(event.theme.header_foreground),
+        ]));
+    }
+
+    let [lines_area, failure_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(failure_area, buf);
+    }
+}
+
+fn activity_task_scheduled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskScheduledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas = layout::Layout::vertical([
+        layout::Constraint::Length(10),
+        layout::Constraint::Fill(1),
+        layout::Constraint::Fill(1),
+        layout::Constraint::Fill(1),
+    ])
+    .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Activity ID: ".into(),
+            text::Span::from(&attrs.activity_id),
+        ]),
+        text::Line::from(vec![
+            "Activity type: ".into(),
+            text::Span::from(if let Some(activity_type) = &attrs.activity_type {
+                &activity_type.name
+            } else {
+                "-"
+            }),
+        ]),
+        text::Line::from(vec![
+            "Task queue name: ".into(),
+            text::Span::from(&attrs.task_queue.as_ref().unwrap().name),
+        ]),
+        text::Line::from(vec![
+            "Task queue kind: ".into(),
+            text::Span::from(task_queue_kind_as_str(
+                attrs.task_queue.as_ref().unwrap().kind,
+            )),
+        ]),
+        text::Line::from(vec![
+            "Start to close timeout: ".into(),
+            text::Span::from(humanize_optional_duration(attrs.start_to_close_timeout)),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(format!("{}", attrs.workflow_task_completed_event_id)),
+        ]),
+        text::Line::from(vec![
+            "Use workflow build ID: ".into(),
+            text::Span::from(format!("{}", attrs.use_workflow_build_id)),
+        ]),
+        text::Line::from(vec![
+            "Retry policy initial interval: ".into(),
+            text::Span::from(humanize_optional_duration(
+                attrs
+                    .retry_policy
+                    .as_ref()
+                    .and_then(|retry_policy| retry_policy.initial_interval),
+            )),
+        ]),
+        text::Line::from(vec![
+            "Retry policy backoff coefficient: ".into(),
+            text::Span::from(if let Some(retry_policy) = attrs.retry_policy.as_ref() {
+                format!("{}", retry_policy.backoff_coefficient)
+            } else {
+                "-".to_owned()
+            }),
+        ]),
+        text::Line::from(vec![
+            "Retry policy maximum interval: ".into(),
+            text::Span::from(humanize_optional_duration(
+                attrs
+                    .retry_policy
+                    .as_ref()
+                    .and_then(|retry_policy| retry_policy.maximum_interval),
+            )),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(retry_policy) = attrs.retry_policy.as_ref() {
+        // Using `collections::BTreeMap` for consistent order.
+        let non_retryable_error_types: collections::BTreeMap<String, String> = retry_policy
+            .non_retryable_error_types
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (format!("{}", i), e.to_string()))
+            .collect();
+        let pretty_non_retryable_error_types =
+            serde_json::to_string_pretty(&non_retryable_error_types).unwrap();
+
+        widgets::Paragraph::new(pretty_non_retryable_error_types)
+            .block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .title(
+                        "Retry policy non retryable error types".fg(event.theme.header_foreground),
+                    )
+                    .border_style(style::Style::new().fg(event.theme.border)),
+            )
+            .fg(event.theme.foreground)
+            .wrap(widgets::Wrap { trim: false })
+            .render(areas[1], buf);
+    }
+
+    if let Some(header) = attrs.header.as_ref() {
+        let headers: collections::HashMap<String, String> = header
+            .fields
+            .iter()
+            .map(|(k, v)| {
+                let payload = PayloadWidget::borrowed(v, "Header", event.theme);
+                (k.to_string(), payload.to_string_pretty())
+            })
+            .collect();
+        let pretty_header = serde_json::to_string_pretty(&headers).unwrap();
+
+        widgets::Paragraph::new(pretty_header)
+            .block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .title("Header".fg(event.theme.header_foreground))
+                    .border_style(style::Style::new().fg(event.theme.border)),
+            )
+            .fg(event.theme.foreground)
+            .wrap(widgets::Wrap { trim: false })
+            .render(areas[2], buf);
+    }
+
+    if let Some(payloads) = attrs.input.as_ref() {
+        for p in payloads.payloads.iter().take(1) {
+            let payload = event.payload(p, "Input");
+            payload.render(areas[3], buf);
+        }
+    }
+}
+
+fn activity_task_started(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskStartedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Request ID: ".into(),
+            text::Span::from(&attrs.request_id),
+        ]),
+        text::Line::from(vec![
+            "Attempt: ".into(),
+            text::Span::from(attrs.attempt.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Worker version: ".into(),
+            text::Span::from(match &attrs.worker_version {
+                Some(ts) => &ts.build_id,
+                None => "-",
+            }),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn activity_task_completed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskCompletedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(3), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(payloads) = attrs.result.as_ref() {
+        for p in payloads.payloads.iter().take(1) {
+            let payload = event.payload(p, "Result");
+            payload.render(areas[1], buf);
+        }
+    }
+}
+
+fn activity_task_cancel_requested(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskCancelRequestedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn activity_task_canceled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskCanceledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(5), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Latest cancel requested event ID: ".into(),
+            text::Span::from(attrs.latest_cancel_requested_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Worker version: ".into(),
+            text::Span::from(if let Some(ts) = &attrs.worker_version {
+                &ts.build_id
+            } else {
+                "-"
+            }),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(payloads) = attrs.details.as_ref() {
+        for p in payloads.payloads.iter().take(1) {
+            let payload = event.payload(p, "Details");
+            payload.render(areas[1], buf);
+        }
+    }
+}
+
+fn activity_task_failed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskFailedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(4), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+        text::Line::from(vec![
+            "Retry state: ".into(),
+            text::Span::from(retry_state_as_str(attrs.retry_state)),
+        ]),
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(areas[1], buf);
+    }
+}
+
+fn activity_task_timed_out(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ActivityTaskTimedOutEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let areas =
+        layout::Layout::vertical([layout::Constraint::Length(3), layout::Constraint::Fill(1)])
+            .split(area);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Retry state: ".into(),
+            text::Span::from(retry_state_as_str(attrs.retry_state)),
+        ]),
+        text::Line::from(vec![
+            "Scheduled event ID: ".into(),
+            text::Span::from(attrs.scheduled_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(areas[0], buf);
+
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(areas[1], buf);
+    }
+}
+
+/// Lines identifying a child workflow, with a hint to open it.
+fn child_workflow_lines<'a>(
+    event: &EventWidget,
+    namespace: &'a str,
+    workflow_id: &'a str,
+    run_id: Option<&'a str>,
+    workflow_type: Option<&'a common::WorkflowType>,
+) -> Vec<text::Line<'a>> {
+    let mut lines = vec![
+        text::Line::from(vec!["Namespace: ".into(), text::Span::from(namespace)]),
+        text::Line::from(vec![
+            "Workflow ID: ".into(),
+            text::Span::from(workflow_id).bold(),
+            " (press Enter to open)".fg(event.theme.header_foreground),
+        ]),
+    ];
+    if let Some(run_id) = run_id {
+        lines.push(text::Line::from(vec![
+            "Run ID: ".into(),
+            text::Span::from(run_id),
+        ]));
+    }
+    lines.push(text::Line::from(vec![
+        "Workflow type name: ".into(),
+        text::Span::from(match workflow_type {
+            Some(workflow_type) => &workflow_type.name,
+            None => "-",
+        }),
+    ]));
+    lines
+}
+
+fn start_child_workflow_execution_initiated(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::StartChildWorkflowExecutionInitiatedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = child_workflow_lines(
+        event,
+        &attrs.namespace,
+        &attrs.workflow_id,
+        None,
+        attrs.workflow_type.as_ref(),
+    );
+    lines.push(text::Line::from(vec![
+        "Task queue name: ".into(),
+        text::Span::from(match &attrs.task_queue {
+            Some(task_queue) => &task_queue.name,
+            None => "-",
+        }),
+    ]));
+    lines.push(text::Line::from(vec![
+        "Workflow task completed event ID: ".into(),
+        text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+    ]));
+
+    let [lines_area, input_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    if let Some(payloads) = attrs.input.as_ref() {
+        for p in payloads.payloads.iter().take(1) {
+            let payload = event.payload(p, "Input");
+            payload.render(input_area, buf);
+        }
+    }
+}
+
+fn start_child_workflow_execution_failed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::StartChildWorkflowExecutionFailedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let cause = enums::StartChildWorkflowExecutionFailedCause::try_from(attrs.cause)
+        .unwrap_or(enums::StartChildWorkflowExecutionFailedCause::Unspecified);
+
+    let lines = vec![
+        text::Line::from(vec![
+            "Cause: ".into(),
+            text::Span::from(cause.as_str_name()),
+        ]),
+        text::Line::from(vec![
+            "Workflow ID: ".into(),
+            text::Span::from(&attrs.workflow_id),
+        ]),
+        text::Line::from(vec![
+            "Workflow type name: ".into(),
+            text::Span::from(if let Some(workflow_type) = &attrs.workflow_type {
+                &workflow_type.name
+            } else {
+                "-"
+            }),
+        ]),
+        text::Line::from(vec![
+            "Namespace: ".into(),
+            text::Span::from(&attrs.namespace),
+        ]),
+        text::Line::from(vec![
+            "Initiated event ID: ".into(),
+            text::Span::from(attrs.initiated_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn workflow_execution_signaled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::WorkflowExecutionSignaledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = vec![
+        text::Line::from(vec![
+            "Signal name: ".into(),
+            text::Span::from(&attrs.signal_name),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+    ];
+    if let Some(execution) = &attrs.external_workflow_execution {
+        lines.push(text::Line::from(vec![
+            "Sent by workflow ID: ".into(),
+            text::Span::from(&execution.workflow_id),
+        ]));
+    }
+    let inputs = attrs
+        .input
+        .as_ref()
+        .map(|payloads| payloads.payloads.as_slice())
+        .unwrap_or_default();
+    if inputs.is_empty() {
+        lines.push(text::Line::from("No input"));
+    }
+
+    let [lines_area, inputs_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    let inputs_areas = layout::Layout::vertical(inputs.iter().map(|_| layout::Constraint::Fill(1)))
+        .split(inputs_area);
+    for (i, (p, input_area)) in inputs.iter().zip(inputs_areas.iter()).enumerate() {
+        let title = match inputs.len() {
+            1 => "Input".to_string(),
+            _ => format!("Input {}", i + 1),
+        };
+        event.payload(p, &title).render(*input_area, buf);
+    }
+}
+
+/// Markers are recorded by SDKs for side effects, versions and local activities, with their
+/// data in `details` by key.
+fn marker_recorded(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::MarkerRecordedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let mut lines = vec![
+        text::Line::from(vec![
+            "Marker name: ".into(),
+            text::Span::from(&attrs.marker_name),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+    ];
+
+    let mut keys: Vec<&String> = attrs.details.keys().collect();
+    keys.sort();
+    let details: Vec<(String, _)> = keys
+        .into_iter()
+        .flat_map(|key| {
+            let payloads = &attrs.details[key].payloads;
+            payloads.iter().enumerate().map(move |(i, p)| {
+                let title = match payloads.len() {
+                    1 => format!("Details: {}", key),
+                    _ => format!("Details: {} {}", key, i + 1),
+                };
+                (title, p)
+            })
+        })
+        .collect();
+    if details.is_empty() {
+        lines.push(text::Line::from("No details"));
+    }
+
+    let [lines_area, details_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    // A local activity that failed records its failure along with its details
+    let areas = layout::Layout::vertical(
+        details
+            .iter()
+            .map(|_| layout::Constraint::Fill(1))
+            .chain(attrs.failure.iter().map(|_| layout::Constraint::Fill(1))),
+    )
+    .split(details_area);
+    for ((title, p), detail_area) in details.iter().zip(areas.iter()) {
+        event.payload(p, title).render(*detail_area, buf);
+    }
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(areas[details.len()], buf);
+    }
+}
+
+fn child_workflow_execution_started(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ChildWorkflowExecutionStartedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let execution = attrs.workflow_execution.clone().unwrap_or_default();
+    let mut lines = child_workflow_lines(
+        event,
+        &attrs.namespace,
+        &execution.workflow_id,
+        Some(&execution.run_id),
+        attrs.workflow_type.as_ref(),
+    );
+    lines.push(text::Line::from(vec![
+        "Initiated event ID: ".into(),
+        text::Span::from(attrs.initiated_event_id.to_string()),
+    ]));
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn child_workflow_execution_completed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ChildWorkflowExecutionCompletedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let execution = attrs.workflow_execution.clone().unwrap_or_default();
+    let mut lines = child_workflow_lines(
+        event,
+        &attrs.namespace,
+        &execution.workflow_id,
+        Some(&execution.run_id),
+        attrs.workflow_type.as_ref(),
+    );
+    lines.push(text::Line::from(vec![
+        "Started event ID: ".into(),
+        text::Span::from(attrs.started_event_id.to_string()),
+    ]));
+    let results = attrs
+        .result
+        .as_ref()
+        .map(|payloads| payloads.payloads.as_slice())
+        .unwrap_or_default();
+    if results.is_empty() {
+        lines.push(text::Line::from("No result"));
+    }
+
+    let [lines_area, results_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    let results_areas =
+        layout::Layout::vertical(results.iter().map(|_| layout::Constraint::Fill(1)))
+            .split(results_area);
+    for (i, (p, result_area)) in results.iter().zip(results_areas.iter()).enumerate() {
+        let title = match results.len() {
+            1 => "Result".to_string(),
+            _ => format!("Result {}", i + 1),
+        };
+        event.payload(p, &title).render(*result_area, buf);
+    }
+}
+
+fn child_workflow_execution_failed(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::ChildWorkflowExecutionFailedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let execution = attrs.workflow_execution.clone().unwrap_or_default();
+    let mut lines = child_workflow_lines(
+        event,
+        &attrs.namespace,
+        &execution.workflow_id,
+        Some(&execution.run_id),
+        attrs.workflow_type.as_ref(),
+    );
+    lines.push(text::Line::from(vec![
+        "Retry state: ".into(),
+        text::Span::from(retry_state_as_str(attrs.retry_state)),
+    ]));
+    lines.push(text::Line::from(vec![
+        "Started event ID: ".into(),
+        text::Span::from(attrs.started_event_id.to_string()),
+    ]));
+
+    let [lines_area, failure_area] = layout::Layout::vertical([
+        layout::Constraint::Length(lines.len() as u16),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(area);
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(lines_area, buf);
+
+    if let Some(failure) = &attrs.failure {
+        let failure = FailureWidget::from(failure);
+        failure.render(failure_area, buf);
+    }
+}
+
+fn timer_started(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::TimerStartedEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec!["Timer ID: ".into(), text::Span::from(&attrs.timer_id)]),
+        text::Line::from(vec![
+            "Start to fire timeout: ".into(),
+            text::Span::from(humanize_optional_duration(attrs.start_to_fire_timeout)),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn timer_fired(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::TimerFiredEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec!["Timer ID: ".into(), text::Span::from(&attrs.timer_id)]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
+
+fn timer_canceled(
+    event: &EventWidget,
+    attributes: &Attributes,
+    area: layout::Rect,
+    buf: &mut buffer::Buffer,
+) {
+    let Attributes::TimerCanceledEventAttributes(attrs) = attributes else {
+        return;
+    };
+
+    let lines = vec![
+        text::Line::from(vec!["Timer ID: ".into(), text::Span::from(&attrs.timer_id)]),
+        text::Line::from(vec![
+            "Started event ID: ".into(),
+            text::Span::from(attrs.started_event_id.to_string()),
+        ]),
+        text::Line::from(vec![
+            "Workflow task completed event ID: ".into(),
+            text::Span::from(attrs.workflow_task_completed_event_id.to_string()),
+        ]),
+        text::Line::from(vec!["Identity: ".into(), text::Span::from(&attrs.identity)]),
+    ];
+    widgets::Paragraph::new(lines)
+        .fg(event.theme.foreground)
+        .render(area, buf);
+}
@@ -1,19 +1,106 @@
+use std::collections;
 use std::sync;
 
 use crossterm::event;
 use ratatui::{buffer, layout, style, style::Stylize, text, widgets};
-use temporal_client::{self, WorkflowClientTrait};
-use temporal_sdk_core_protos::temporal::api::workflowservice::v1 as service;
+use temporal_client::{self, tonic, WorkflowClientTrait, WorkflowService};
+use temporal_sdk_core_protos::temporal::api::{
+    common::v1 as temporal_common, enums::v1 as enums, history::v1 as history,
+    workflowservice::v1 as service,
+};
 use tokio::sync::mpsc;
-use tokio::task;
 use tokio::time;
 
+use crate::clipboard;
+use crate::keymap::Action;
+use crate::settings::{EnterAction, Settings};
 use crate::theme::Theme;
-use crate::widgets::common::{LoadingState, Message, WorkflowExecution};
+use crate::widgets::common::{
+    humanize_duration, render_error_banner, truncate_middle, BackgroundTasks, LoadingState,
+    Message, PendingJump, ReloadQueue, TimeFormat, WorkflowExecution, COPIED_NOTICE_DURATION,
+};
+use crate::widgets::dashboard::DashboardWidget;
+use crate::widgets::namespace::NamespaceWidget;
+use crate::widgets::query::{self, Validation};
 use crate::widgets::workflow::WorkflowWidget;
-use crate::widgets::{Keybindable, ViewWidget};
+use crate::widgets::{Keybindable, Navigation, ViewWidget};
 
 const ITEM_HEIGHT: usize = 1;
+/// How much `+` and `-` change the page size by, and the bounds they keep it within.
+const PAGE_SIZE_STEP: u32 = 10;
+const MIN_PAGE_SIZE: u32 = 10;
+const MAX_PAGE_SIZE: u32 = 200;
+/// The statuses filtered to with the number keys, `0` clears the filter.
+const STATUS_FILTER_KEYS: [(char, &str); 6] = [
+    ('1', "Running"),
+    ('2', "Completed"),
+    ('3', "Failed"),
+    ('4', "TimedOut"),
+    ('5', "Canceled"),
+    ('6', "Terminated"),
+];
+
+/// Hours back the start time filter goes, cycled through with `s`.
+const STARTED_WITHIN_HOURS: [i64; 3] = [1, 24, 7 * 24];
+
+/// The start of `workflow_id` up to its last separator, like `order-` of `order-1234`, shared by
+/// workflows started the same way. IDs without a separator are their own prefix.
+fn workflow_id_prefix(workflow_id: &str) -> &str {
+    match workflow_id.rfind(['-', '_', ':', '/', '.']) {
+        Some(i) => &workflow_id[..=i],
+        None => workflow_id,
+    }
+}
+
+/// Why a workflow was closed, from the attributes of its close event, if they record it.
+fn close_reason(attributes: history::history_event::Attributes) -> Option<String> {
+    match attributes {
+        history::history_event::Attributes::WorkflowExecutionTerminatedEventAttributes(attrs) => {
+            Some(format!(
+                "Terminated by {}: {}",
+                attrs.identity, attrs.reason
+            ))
+        }
+        history::history_event::Attributes::WorkflowExecutionCanceledEventAttributes(attrs) => {
+            let details = attrs
+                .details
+                .iter()
+                .flat_map(|details| details.payloads.iter())
+                .map(|p| String::from_utf8_lossy(&p.data).into_owned())
+                .collect::<Vec<String>>()
+                .join(", ");
+            (!details.is_empty()).then(|| format!("Canceled with details: {}", details))
+        }
+        _ => None,
+    }
+}
+
+/// Kinds of values displayed in a table column, which determine how they are aligned.
+#[derive(Debug, Clone, Copy)]
+enum ColumnType {
+    Text,
+    Time,
+}
+
+impl ColumnType {
+    /// Times are right-aligned so that they line up.
+    fn alignment(&self) -> layout::Alignment {
+        match self {
+            ColumnType::Text => layout::Alignment::Left,
+            ColumnType::Time => layout::Alignment::Right,
+        }
+    }
+}
+
+/// Header, type and width of each column of the workflow table.
+const COLUMNS: [(&str, ColumnType, u16); 6] = [
+    ("Status", ColumnType::Text, 18),
+    ("Type", ColumnType::Text, 32),
+    ("Workflow ID", ColumnType::Text, 64),
+    ("Task Queue", ColumnType::Text, 32),
+    ("Start Time", ColumnType::Time, 32),
+    ("Close Time", ColumnType::Time, 32),
+];
 
 /// Modes the [`WorkflowTableWidget`] can be in.
 #[derive(Debug, Clone, Copy)]
@@ -38,7 +125,12 @@ impl<'m> Mode {
 pub struct QueryInput {
     query: Option<String>,
     placeholder: String,
+    /// What is being input, to tell about errors in it.
+    subject: String,
+    /// Byte position of the character under the cursor, always on a character boundary.
     cursor: usize,
+    /// The last syntax error found by [`QueryInput::validate`] and the byte position it was found at.
+    error: Option<(String, usize)>,
     theme: Theme,
 }
 
@@ -47,7 +139,9 @@ impl Default for QueryInput {
         Self {
             query: None,
             placeholder: "Enter a query...".to_string(),
+            subject: "query".to_string(),
             cursor: 0,
+            error: None,
             theme: Theme::default(),
         }
     }
@@ -58,40 +152,101 @@ impl QueryInput {
         Self {
             query: None,
             placeholder: placeholder.to_string(),
+            subject: "query".to_string(),
             cursor: 0,
+            error: None,
             theme,
         }
     }
 
+    /// Input something other than a query, like "JSON", as told in errors.
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.subject = subject.to_string();
+        self
+    }
+
+    /// Report an error found in the input by other means than [`QueryInput::validate`], at byte
+    /// `position`, until the next key press.
+    pub fn set_error(&mut self, message: &str, position: usize) {
+        let query = self.query.as_deref().unwrap_or_default();
+        let mut position = position.min(query.len().saturating_sub(1));
+        while !query.is_char_boundary(position) {
+            position -= 1;
+        }
+        self.error = Some((message.to_string(), position));
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Replace the input with `query`, with the cursor at its end.
+    pub fn set_query(&mut self, query: &str) {
+        let query = query.trim();
+        self.error = None;
+        if query.is_empty() {
+            self.query = None;
+            self.cursor = 0;
+        } else {
+            // Like when typing, keep a trailing space for the cursor to rest on
+            self.query = Some(format!("{} ", query));
+            self.cursor = query.len();
+        }
+    }
+
     pub fn query(&self) -> String {
         match &self.query {
             Some(q) => q.trim().to_owned(),
             None => "".to_owned(),
         }
     }
+
+    /// Check the query for syntax errors, keeping track of any so they can be rendered.
+    ///
+    /// Returns whether the query is worth sending: queries the server may still reject, like
+    /// those using custom search attributes, are let through.
+    pub fn validate(&mut self) -> bool {
+        let validation = match &self.query {
+            Some(q) => query::validate(q),
+            None => Validation::Valid,
+        };
+
+        match validation {
+            Validation::Invalid { message, position } => {
+                log::debug!(widget = "QueryInput", position = position; "Invalid query: {}", message);
+                self.error = Some((message, position));
+                false
+            }
+            Validation::Inconclusive { message } => {
+                log::debug!(widget = "QueryInput"; "Query may be invalid: {}", message);
+                self.error = None;
+                true
+            }
+            Validation::Valid => {
+                self.error = None;
+                true
+            }
+        }
+    }
 }
 
 impl Keybindable for QueryInput {
-    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<ViewWidget> {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        self.error = None;
         match key {
             event::KeyEvent {
                 code: event::KeyCode::Char(c),
                 ..
             } => {
                 if let Some(query) = self.query.as_mut() {
-                    if self.cursor == query.len() - 1 {
-                        query.pop();
-                        query.push(c);
-                        query.push(' ');
-                    } else {
-                        query.insert(self.cursor, c);
-                    }
-                    self.cursor += 1;
+                    // Before the character under the cursor, or the trailing space at the end
+                    query.insert(self.cursor, c);
+                    self.cursor += c.len_utf8();
                 } else {
                     let mut query = c.to_string();
                     query.push(' ');
                     self.query = Some(query);
-                    self.cursor = 1;
+                    self.cursor = c.len_utf8();
                 }
             }
             event::KeyEvent {
@@ -99,15 +254,9 @@ impl Keybindable for QueryInput {
                 ..
             } => {
                 if let Some(query) = self.query.as_mut() {
-                    if query.len() > 1 {
-                        if self.cursor == query.len() - 1 {
-                            query.pop();
-                            query.pop();
-                            query.push(' ');
-                        } else {
-                            query.remove(self.cursor);
-                        }
-                        self.cursor -= 1;
+                    if let Some((previous, _)) = query[..self.cursor].char_indices().next_back() {
+                        query.remove(previous);
+                        self.cursor = previous;
                     }
                 }
                 self.query.take_if(|v| v.len() == 1);
@@ -116,9 +265,9 @@ impl Keybindable for QueryInput {
                 code: event::KeyCode::Left,
                 ..
             } => {
-                if let Some(_) = self.query.as_ref() {
-                    if self.cursor > 0 {
-                        self.cursor -= 1;
+                if let Some(query) = self.query.as_ref() {
+                    if let Some((previous, _)) = query[..self.cursor].char_indices().next_back() {
+                        self.cursor = previous;
                     }
                 }
             }
@@ -127,8 +276,9 @@ impl Keybindable for QueryInput {
                 ..
             } => {
                 if let Some(query) = self.query.as_ref() {
-                    if self.cursor < query.len() {
-                        self.cursor += 1;
+                    // Stop at the trailing space, past the last character
+                    if let Some((next, _)) = query[self.cursor..].char_indices().nth(1) {
+                        self.cursor += next;
                     }
                 }
             }
@@ -144,7 +294,7 @@ impl Keybindable for QueryInput {
 
 impl widgets::Widget for &QueryInput {
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
-        let input_block = widgets::Block::bordered()
+        let mut input_block = widgets::Block::bordered()
             .borders(widgets::Borders::ALL)
             .border_type(widgets::BorderType::Rounded)
             .border_style(style::Style::new().fg(self.theme.border));
@@ -153,66 +303,292 @@ impl widgets::Widget for &QueryInput {
             Some(q) => q.as_str(),
             None => self.placeholder.as_str(),
         };
+        let error_position = self.error.as_ref().map(|(_, position)| *position);
 
-        let [query_start, cursor_char, query_end]: [&str; 3] = [
-            &query_str[..self.cursor],
-            &query_str[self.cursor..self.cursor + 1],
-            &query_str[self.cursor + 1..],
-        ];
-        let query_start_span = text::Span::from(query_start);
-        let cursor_char_span = text::Span::from(cursor_char).underlined();
-        let query_end_span = text::Span::from(query_end);
-        let input_text = widgets::Paragraph::new(text::Line::from_iter([
-            query_start_span,
-            cursor_char_span,
-            query_end_span,
-        ]))
-        .fg(self.theme.foreground)
-        .block(input_block);
+        let spans = query_str.char_indices().map(|(i, c)| {
+            let mut span = text::Span::from(c.to_string());
+            if i == self.cursor {
+                span = span.underlined();
+            }
+            if Some(i) == error_position {
+                span = span.bg(self.theme.failure_background);
+            }
+            span
+        });
+
+        if let Some((message, position)) = self.error.as_ref() {
+            input_block = input_block
+                .border_style(style::Style::new().fg(self.theme.failure_background))
+                .title_bottom(
+                    text::Line::from(format!(
+                        "Invalid {} at column {}: {}",
+                        self.subject,
+                        query_str[..*position].chars().count() + 1,
+                        message
+                    ))
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.failure_background),
+                );
+        }
+
+        let input_text = widgets::Paragraph::new(text::Line::from_iter(spans))
+            .fg(self.theme.foreground)
+            .block(input_block);
 
         widgets::Widget::render(input_text, area, buf);
     }
 }
 
+/// Quick filters narrowing down the listed workflows on top of the free-text query.
+#[derive(Debug, Clone, Default)]
+pub struct QuickFilters {
+    /// An `ExecutionStatus` name, like "Running".
+    pub status: Option<String>,
+    pub workflow_type: Option<String>,
+    pub workflow_id_prefix: Option<String>,
+    pub task_queue: Option<String>,
+    /// Only list children of this workflow.
+    pub parent_workflow_id: Option<String>,
+    /// Only list workflows started within this long before each reload.
+    pub started_within: Option<chrono::TimeDelta>,
+}
+
+impl QuickFilters {
+    fn clauses(&self) -> Vec<String> {
+        self.clauses_at(chrono::Utc::now())
+    }
+
+    fn clauses_at(&self, now: chrono::DateTime<chrono::Utc>) -> Vec<String> {
+        let quote = |value: &str| format!("\"{}\"", value.replace('"', "\\\""));
+        let time = |dt: &chrono::DateTime<chrono::Utc>| {
+            quote(&dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        };
+
+        [
+            self.status
+                .as_ref()
+                .map(|status| format!("ExecutionStatus = {}", quote(status))),
+            self.workflow_type
+                .as_ref()
+                .map(|workflow_type| format!("WorkflowType = {}", quote(workflow_type))),
+            self.workflow_id_prefix
+                .as_ref()
+                .map(|prefix| format!("WorkflowId STARTS_WITH {}", quote(prefix))),
+            self.task_queue
+                .as_ref()
+                .map(|task_queue| format!("TaskQueue = {}", quote(task_queue))),
+            self.parent_workflow_id
+                .as_ref()
+                .map(|workflow_id| format!("ParentWorkflowId = {}", quote(workflow_id))),
+            self.started_within
+                .map(|within| format!("StartTime >= {}", time(&(now - within)))),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Filter to workflows started within the next of [`STARTED_WITHIN_HOURS`], clearing the
+    /// filter after the longest.
+    fn cycle_started_within(&mut self) {
+        let position = self.started_within.and_then(|within| {
+            STARTED_WITHIN_HOURS
+                .iter()
+                .position(|hours| chrono::TimeDelta::hours(*hours) == within)
+        });
+        let next = position.map_or(0, |position| position + 1);
+        self.started_within = STARTED_WITHIN_HOURS
+            .get(next)
+            .map(|hours| chrono::TimeDelta::hours(*hours));
+    }
+
+    /// Compose all active filters and the free-text `query` into a single Visibility query.
+    pub fn compose(&self, query: &str) -> String {
+        let mut clauses = self.clauses();
+        if !query.is_empty() {
+            clauses.push(query.to_owned());
+        }
+
+        match clauses.len() {
+            0 => String::new(),
+            1 => clauses.pop().unwrap(),
+            _ => clauses
+                .iter()
+                .map(|clause| format!("({})", clause))
+                .collect::<Vec<String>>()
+                .join(" AND "),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WorkflowTableWidget {
     state: sync::Arc<sync::RwLock<WorkflowTableState>>,
     temporal_client: sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
     sender: sync::Arc<Option<mpsc::Sender<Message>>>,
-    page_size: u32,
+    tasks: BackgroundTasks,
+    /// How many workflows are listed in each page, changed with `+` and `-`.
+    page_size: sync::Arc<sync::RwLock<u32>>,
     mode: Mode,
     theme: Theme,
     last_reload: sync::Arc<sync::RwLock<Option<time::Instant>>>,
+    reload_queue: ReloadQueue,
     query: sync::Arc<sync::RwLock<QueryInput>>,
+    /// Changing filters doesn't reload, they are only sent once applied.
+    filters: sync::Arc<sync::RwLock<QuickFilters>>,
+    /// Whether to display a summary of the selected workflow below the table.
+    show_preview: bool,
+    /// How start and close times are displayed in the table.
+    time_format: TimeFormat,
+    /// When the selected workflow was last copied to the clipboard, to tell it was.
+    copied_at: Option<time::Instant>,
+    /// A key waiting for the next one to complete a sequence, like the first `g` of `gg`.
+    pending_key: Option<char>,
+    /// Whether the table is being reloaded automatically, to tell next to the last reload.
+    auto_refresh: bool,
+    settings: sync::Arc<Settings>,
 }
 
 #[derive(Debug, Default)]
 struct WorkflowTableState {
     workflow_executions: Vec<WorkflowExecution>,
     next_page_token: Option<Vec<u8>>,
+    /// How many workflows match the query in total, counted on each reload.
+    count: Option<i64>,
     loading_state: LoadingState,
     table_state: widgets::TableState,
     scrollbar_state: widgets::ScrollbarState,
+    /// Workflows pinned to the top of the table regardless of the query, with their last
+    /// known state.
+    pinned: Vec<WorkflowExecution>,
+    /// Why terminated or canceled workflows were closed, by run ID, loaded once selected.
+    close_reasons: collections::HashMap<String, String>,
+    /// Summaries of previewed workflows, or why they couldn't be loaded, by run ID.
+    previews: collections::HashMap<String, Result<WorkflowPreview, String>>,
+    /// Rows fitting in the table when it was last rendered, to jump by pages.
+    page_height: usize,
+    /// Where to move the selection once the page of workflows being loaded arrives.
+    pending_jump: Option<PendingJump>,
+    /// Why the server rejected the last query, shown under the query input until a query is
+    /// accepted.
+    query_error: Option<String>,
+}
+
+/// A summary of a workflow from its describe response, previewed below the table.
+#[derive(Debug, Clone)]
+struct WorkflowPreview {
+    execution: WorkflowExecution,
+    parent_workflow_id: Option<String>,
+    pending_activities: usize,
+    pending_children: usize,
+}
+
+impl WorkflowTableState {
+    fn is_pinned(&self, workflow_id: &str) -> bool {
+        self.pinned.iter().any(|e| e.workflow_id == workflow_id)
+    }
+
+    /// Rows in display order, pinned workflows first followed by the rest of the results.
+    fn rows(&self) -> Vec<(bool, &WorkflowExecution)> {
+        self.pinned
+            .iter()
+            .map(|execution| (true, execution))
+            .chain(
+                self.workflow_executions
+                    .iter()
+                    .filter(|execution| !self.is_pinned(&execution.workflow_id))
+                    .map(|execution| (false, execution)),
+            )
+            .collect()
+    }
+
+    fn len(&self) -> usize {
+        self.rows().len()
+    }
+
+    fn get(&self, index: usize) -> Option<&WorkflowExecution> {
+        self.rows().get(index).map(|(_, execution)| *execution)
+    }
+
+    /// Update pinned workflows that are part of the current results.
+    fn update_pinned(&mut self) {
+        for pinned in self.pinned.iter_mut() {
+            if let Some(execution) = self
+                .workflow_executions
+                .iter()
+                .find(|e| e.workflow_id == pinned.workflow_id)
+            {
+                *pinned = execution.clone();
+            }
+        }
+    }
 }
 
 impl WorkflowTableWidget {
     pub fn new(
         temporal_client: &sync::Arc<temporal_client::RetryClient<temporal_client::Client>>,
+        settings: &sync::Arc<Settings>,
         theme: Theme,
-        page_size: u32,
     ) -> Self {
         Self {
             state: sync::Arc::new(sync::RwLock::new(WorkflowTableState::default())),
             temporal_client: temporal_client.clone(),
             sender: sync::Arc::new(None),
-            page_size,
+            tasks: BackgroundTasks::default(),
+            page_size: sync::Arc::new(sync::RwLock::new(settings.page_size)),
             theme,
             mode: Mode::Normal,
             last_reload: sync::Arc::new(sync::RwLock::new(None)),
+            reload_queue: ReloadQueue::default(),
             query: sync::Arc::new(sync::RwLock::new(QueryInput {
                 theme,
                 ..QueryInput::default()
             })),
+            filters: sync::Arc::new(sync::RwLock::new(QuickFilters::default())),
+            show_preview: false,
+            time_format: TimeFormat::default(),
+            copied_at: None,
+            pending_key: None,
+            auto_refresh: false,
+            settings: settings.clone(),
+        }
+    }
+
+    /// A new, independent [`WorkflowTableWidget`] starting with the same query and filters.
+    pub fn duplicate(&self) -> Self {
+        let duplicate = Self::new(&self.temporal_client, &self.settings, self.theme);
+        *duplicate.page_size.write().unwrap() = *self.page_size.read().unwrap();
+        *duplicate.query.write().unwrap() = self.query.read().unwrap().clone();
+        *duplicate.filters.write().unwrap() = self.filters.read().unwrap().clone();
+        Self {
+            time_format: self.time_format,
+            ..duplicate
+        }
+    }
+
+    /// Start listing the workflows matching `query`.
+    pub fn with_query(self, query: &str) -> Self {
+        self.query.write().unwrap().set_query(query);
+        self
+    }
+
+    /// Start listing only the children of the workflow with `parent_workflow_id`.
+    pub fn with_parent_workflow_id(self, parent_workflow_id: &str) -> Self {
+        self.filters.write().unwrap().parent_workflow_id = Some(parent_workflow_id.to_owned());
+        self
+    }
+
+    /// The Visibility query combining the free-text query with all quick filters.
+    fn query(&self) -> String {
+        let query = self.query.read().unwrap().query();
+        self.filters.read().unwrap().compose(&query)
+    }
+
+    /// A short name for the tab showing this table.
+    pub fn tab_title(&self) -> String {
+        match self.query() {
+            query if query.is_empty() => "All workflows".to_string(),
+            query => query,
         }
     }
 
@@ -221,7 +597,7 @@ impl WorkflowTableWidget {
         *sync::Arc::get_mut(&mut self.sender).unwrap() = Some(tx);
 
         let this = self.clone(); // clone the widget to pass to the background task
-        tokio::spawn(this.fetch_workflows(rx));
+        self.tasks.spawn(this.fetch_workflows(rx));
     }
 
     async fn fetch_workflows(mut self, mut receiver: mpsc::Receiver<Message>) {
@@ -229,16 +605,22 @@ impl WorkflowTableWidget {
         while let Some(message) = receiver.recv().await {
             match message {
                 Message::Reload => {
+                    self.reload_queue.wait(&self.last_reload).await;
                     log::debug!(widget = "WorkflowTableWidget"; "Reloading");
                     self.set_loading_state(LoadingState::Loading);
-                    let query = self.query.read().unwrap().query();
-                    let list_workflow_executions_result = self
-                        .temporal_client
-                        .list_workflow_executions(self.page_size as i32, Vec::new(), query)
-                        .await;
+                    let list_workflow_executions_result =
+                        self.list_workflow_executions(Vec::new()).await;
 
                     match list_workflow_executions_result {
-                        Ok(response) => self.on_reload(response),
+                        Ok(response) => {
+                            self.state.write().unwrap().query_error = None;
+                            self.on_reload(response);
+                            self.refresh_pinned().await;
+                            self.count_workflow_executions().await;
+                        }
+                        Err(e) if e.code() == tonic::Code::InvalidArgument => {
+                            self.on_query_err(e.message())
+                        }
                         Err(e) => self.on_err(anyhow::anyhow!(
                             "list workflow executions request failed: {}",
                             e.to_string()
@@ -248,24 +630,68 @@ impl WorkflowTableWidget {
                 Message::LoadPage { page_token } => {
                     log::debug!(widget = "WorkflowTableWidget"; "Loading page {:?}", page_token);
                     self.set_loading_state(LoadingState::Loading);
-                    let query = self.query.read().unwrap().query();
-                    let list_workflow_executions_result = self
-                        .temporal_client
-                        .list_workflow_executions(self.page_size as i32, page_token, query)
-                        .await;
+                    let list_workflow_executions_result =
+                        self.list_workflow_executions(page_token).await;
 
                     match list_workflow_executions_result {
-                        Ok(response) => self.on_page_load(response),
-                        Err(e) => self.on_err(anyhow::anyhow!(
-                            "list workflow executions request failed: {}",
-                            e.to_string()
-                        )),
+                        Ok(response) => {
+                            self.on_page_load(response);
+                            self.apply_pending_jump().await;
+                        }
+                        Err(e) => {
+                            self.state.write().unwrap().pending_jump = None;
+                            self.on_err(anyhow::anyhow!(
+                                "list workflow executions request failed: {}",
+                                e.to_string()
+                            ))
+                        }
                     }
                 }
+                Message::Describe
+                | Message::Cancel { .. }
+                | Message::Signal { .. }
+                | Message::StackTrace
+                | Message::SetTheme(_) => {}
+                Message::LoadCloseReason {
+                    workflow_id,
+                    run_id,
+                } => self.load_close_reason(workflow_id, run_id).await,
+                Message::LoadPreview {
+                    workflow_id,
+                    run_id,
+                } => self.load_preview(workflow_id, run_id).await,
             }
         }
     }
 
+    /// List a page of workflows matching the query.
+    async fn list_workflow_executions(
+        &self,
+        page_token: Vec<u8>,
+    ) -> Result<service::ListWorkflowExecutionsResponse, tonic::Status> {
+        let page_size = *self.page_size.read().unwrap() as i32;
+        self.temporal_client
+            .list_workflow_executions(page_size, page_token, self.query())
+            .await
+    }
+
+    /// Count the workflows matching the query. Counting is only informative, so failing to do it
+    /// doesn't fail the reload.
+    async fn count_workflow_executions(&self) {
+        let count = match self
+            .temporal_client
+            .count_workflow_executions(self.query())
+            .await
+        {
+            Ok(response) => Some(response.count),
+            Err(e) => {
+                log::warn!(widget = "WorkflowTableWidget"; "Failed to count workflows: {}", e);
+                None
+            }
+        };
+        self.state.write().unwrap().count = count;
+    }
+
     fn set_loading_state(&mut self, loading_state: LoadingState) {
         match loading_state {
             LoadingState::Reloaded => {
@@ -314,28 +740,396 @@ impl WorkflowTableWidget {
         }
 
         state.workflow_executions.extend(executions);
+        state.update_pinned();
+
+        if clear {
+            // Nothing can be selected when no workflows match
+            let selected = (state.len() > 0).then_some(0);
+            state.table_state.select(selected);
+            state.scrollbar_state = state.scrollbar_state.position(0);
+        }
+    }
+
+    /// Describe pinned workflows that fell out of the current results to refresh their status.
+    async fn refresh_pinned(&mut self) {
+        let missing: Vec<String> = {
+            let state = self.state.read().unwrap();
+            state
+                .pinned
+                .iter()
+                .filter(|pinned| {
+                    !state
+                        .workflow_executions
+                        .iter()
+                        .any(|e| e.workflow_id == pinned.workflow_id)
+                })
+                .map(|pinned| pinned.workflow_id.clone())
+                .collect()
+        };
+
+        for workflow_id in missing {
+            let execution = self
+                .temporal_client
+                .describe_workflow_execution(workflow_id.clone(), None)
+                .await
+                .map_err(|e| anyhow::anyhow!("describe workflow execution failed: {}", e))
+                .and_then(|response| {
+                    response
+                        .workflow_execution_info
+                        .ok_or(anyhow::anyhow!("workflow has no execution info"))
+                })
+                .and_then(WorkflowExecution::try_from);
+
+            match execution {
+                Ok(execution) => {
+                    let mut state = self.state.write().unwrap();
+                    if let Some(pinned) = state
+                        .pinned
+                        .iter_mut()
+                        .find(|e| e.workflow_id == workflow_id)
+                    {
+                        *pinned = execution;
+                    }
+                }
+                // Keep showing the last known state, a pinned workflow may have been deleted.
+                Err(e) => log::warn!(
+                    widget = "WorkflowTableWidget",
+                    method = "refresh_pinned";
+                    "Failed to refresh pinned workflow {}: {}", workflow_id, e
+                ),
+            }
+        }
+    }
+
+    /// Read the close event of a terminated or canceled workflow to find why it was closed.
+    async fn load_close_reason(&mut self, workflow_id: String, run_id: String) {
+        let mut temporal_client = (*self.temporal_client).clone();
+        // The close event is the last one, so reading the history backwards finds it first
+        let request = service::GetWorkflowExecutionHistoryReverseRequest {
+            namespace: self.temporal_client.namespace().to_string(),
+            execution: Some(temporal_common::WorkflowExecution {
+                workflow_id: workflow_id.clone(),
+                run_id: run_id.clone(),
+            }),
+            maximum_page_size: 1,
+            ..Default::default()
+        };
+        let reason = match temporal_client
+            .get_workflow_execution_history_reverse(tonic::Request::new(request))
+            .await
+            .map(tonic::Response::into_inner)
+        {
+            Ok(response) => response
+                .history
+                .and_then(|h| h.events.into_iter().next())
+                .and_then(|event| close_reason(event.attributes?))
+                .unwrap_or_else(|| "No reason recorded".to_string()),
+            Err(e) => {
+                log::warn!(
+                    widget = "WorkflowTableWidget",
+                    method = "load_close_reason";
+                    "Failed to load history of {}: {}", workflow_id, e
+                );
+                format!("Failed to load close reason: {}", e.message())
+            }
+        };
+
+        let mut state = self.state.write().unwrap();
+        state.close_reasons.insert(run_id, reason);
+    }
+
+    /// Load the summary of a workflow for the preview panel, or why it couldn't be loaded.
+    async fn load_preview(&self, workflow_id: String, run_id: String) {
+        let preview = self.describe_preview(&workflow_id, &run_id).await;
+        if let Err(e) = &preview {
+            log::warn!(
+                widget = "WorkflowTableWidget",
+                method = "load_preview";
+                "Failed to preview {}: {}", workflow_id, e
+            );
+        }
+        let mut state = self.state.write().unwrap();
+        state.previews.insert(run_id, preview);
+    }
+
+    /// Describe a workflow to summarize it in the preview panel.
+    async fn describe_preview(
+        &self,
+        workflow_id: &str,
+        run_id: &str,
+    ) -> Result<WorkflowPreview, String> {
+        let response = self
+            .temporal_client
+            .describe_workflow_execution(workflow_id.to_string(), Some(run_id.to_string()))
+            .await
+            .map_err(|e| format!("Failed to describe workflow: {}", e))?;
+
+        let info = response
+            .workflow_execution_info
+            .ok_or("Workflow execution info missing from describe response".to_string())?;
+        let parent_workflow_id = info
+            .parent_execution
+            .as_ref()
+            .map(|parent| parent.workflow_id.clone());
+        let execution = WorkflowExecution::try_from(info)
+            .map_err(|e| format!("Invalid workflow execution: {}", e))?;
+
+        Ok(WorkflowPreview {
+            execution,
+            parent_workflow_id,
+            pending_activities: response.pending_activities.len(),
+            pending_children: response.pending_children.len(),
+        })
+    }
+
+    /// The selected workflow, if the preview is displayed and the workflow's hasn't been loaded.
+    fn selected_without_preview(&self) -> Option<WorkflowExecution> {
+        if !self.show_preview {
+            return None;
+        }
+        let execution = self.get_selected_workflow_execution()?;
+        let is_loaded = {
+            let state = self.state.read().unwrap();
+            state.previews.contains_key(&execution.run_id)
+        };
+        (!is_loaded).then_some(execution)
+    }
+
+    /// Request loading the preview of the selected workflow, if the preview is displayed and it
+    /// hasn't been loaded yet.
+    pub async fn request_preview(&self) {
+        if let Some(execution) = self.selected_without_preview() {
+            let sender = self.sender.as_ref().clone();
+            sender
+                .unwrap()
+                .send(Message::LoadPreview {
+                    workflow_id: execution.workflow_id,
+                    run_id: execution.run_id,
+                })
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Open the selected workflow's view.
+    fn view_selected_workflow(&self) -> Option<ViewWidget> {
+        let execution = self.get_selected_workflow_execution()?;
+        let workflow_widget = WorkflowWidget::new(
+            &self.temporal_client,
+            &self.settings,
+            &execution.workflow_id,
+            Some(&execution.run_id),
+            self.theme,
+        )
+        .with_execution(execution);
+        Some(ViewWidget::Workflow(workflow_widget))
+    }
+
+    /// The selected workflow, if it was terminated or canceled and why hasn't been loaded.
+    fn selected_without_close_reason(&self) -> Option<WorkflowExecution> {
+        let execution = self.get_selected_workflow_execution()?;
+        let is_loaded = {
+            let state = self.state.read().unwrap();
+            state.close_reasons.contains_key(&execution.run_id)
+        };
+        let has_close_reason = matches!(
+            execution.status,
+            enums::WorkflowExecutionStatus::Terminated | enums::WorkflowExecutionStatus::Canceled
+        );
+        (has_close_reason && !is_loaded).then_some(execution)
+    }
 
-        if !state.workflow_executions.is_empty() && clear {
-            state.table_state.select(Some(0));
+    /// Request loading the close reason of the selected workflow, if it was terminated or
+    /// canceled and it hasn't been loaded yet.
+    pub async fn request_close_reason(&self) {
+        if let Some(execution) = self.selected_without_close_reason() {
+            let sender = self.sender.as_ref().clone();
+            sender
+                .unwrap()
+                .send(Message::LoadCloseReason {
+                    workflow_id: execution.workflow_id,
+                    run_id: execution.run_id,
+                })
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Pin the selected workflow to the top of the table, or unpin it if already pinned.
+    pub fn toggle_pin_selected(&mut self) {
+        let mut state = self.state.write().unwrap();
+        let Some(execution) = state
+            .table_state
+            .selected()
+            .and_then(|i| state.get(i))
+            .cloned()
+        else {
+            return;
+        };
+
+        if state.is_pinned(&execution.workflow_id) {
+            state
+                .pinned
+                .retain(|e| e.workflow_id != execution.workflow_id);
+            let last = state.len().saturating_sub(1);
+            let selected = state.table_state.selected().map(|i| i.min(last));
+            state.table_state.select(selected);
+        } else {
+            state.pinned.push(execution);
+            let selected = state.pinned.len() - 1;
+            state.table_state.select(Some(selected));
         }
     }
 
     fn on_err(&mut self, err: anyhow::Error) {
+        log::error!(widget = "WorkflowTableWidget"; "{}", err);
         self.set_loading_state(LoadingState::Error(err.to_string()));
-        panic!("error");
     }
 
+    /// Keep the previous results when the server rejects the query, showing why under the query
+    /// input instead of replacing the table with an error.
+    fn on_query_err(&mut self, message: &str) {
+        log::warn!(widget = "WorkflowTableWidget"; "Invalid query: {}", message);
+        let mut state = self.state.write().unwrap();
+        state.query_error = Some(message.to_string());
+        state.loading_state = LoadingState::Idle;
+    }
+
+    /// Reload in the background, unless a reload is already waiting to start.
     pub async fn reload(&self) {
+        if !self.reload_queue.push() {
+            log::debug!(widget = "WorkflowTableWidget"; "Reload already queued");
+            return;
+        }
+
         let sender = self.sender.as_ref().clone();
         sender.unwrap().send(Message::Reload).await.unwrap();
     }
 
+    /// Filter to the task queue of the selected workflow, or clear the filter if already set.
+    pub async fn toggle_task_queue_filter(&mut self) {
+        let task_queue = self
+            .get_selected_workflow_execution()
+            .map(|execution| execution.task_queue);
+        {
+            let mut filters = self.filters.write().unwrap();
+            filters.task_queue = match filters.task_queue {
+                Some(_) => None,
+                None => task_queue,
+            };
+        }
+        self.apply_filters().await;
+    }
+
+    /// Filter to the type of the selected workflow, or clear the filter if already set.
+    pub async fn toggle_workflow_type_filter(&mut self) {
+        let workflow_type = self
+            .get_selected_workflow_execution()
+            .map(|execution| execution.r#type);
+        {
+            let mut filters = self.filters.write().unwrap();
+            filters.workflow_type = match filters.workflow_type {
+                Some(_) => None,
+                None => workflow_type,
+            };
+        }
+        self.apply_filters().await;
+    }
+
+    /// Filter to workflows whose ID starts like the selected workflow's, or clear the filter if
+    /// already set.
+    pub async fn toggle_workflow_id_prefix_filter(&mut self) {
+        let prefix = self
+            .get_selected_workflow_execution()
+            .map(|execution| workflow_id_prefix(&execution.workflow_id).to_owned());
+        {
+            let mut filters = self.filters.write().unwrap();
+            filters.workflow_id_prefix = match filters.workflow_id_prefix {
+                Some(_) => None,
+                None => prefix,
+            };
+        }
+        self.apply_filters().await;
+    }
+
+    /// Filter to workflows started within the next longer window, or clear the filter after the
+    /// longest.
+    pub async fn cycle_started_within_filter(&mut self) {
+        self.filters.write().unwrap().cycle_started_within();
+        self.apply_filters().await;
+    }
+
+    /// Filter to the children of the selected workflow, or clear the filter if already set.
+    pub async fn toggle_parent_filter(&mut self) {
+        let workflow_id = self
+            .get_selected_workflow_execution()
+            .map(|execution| execution.workflow_id);
+        {
+            let mut filters = self.filters.write().unwrap();
+            filters.parent_workflow_id = match filters.parent_workflow_id {
+                Some(_) => None,
+                None => workflow_id,
+            };
+        }
+        self.apply_filters().await;
+    }
+
+    /// Filter to the status bound to `key` in [`STATUS_FILTER_KEYS`], replacing any status
+    /// filter, or clear it with `0`.
+    pub async fn set_status_filter(&mut self, key: char) {
+        let status = STATUS_FILTER_KEYS
+            .iter()
+            .find(|(status_key, _)| *status_key == key)
+            .map(|(_, status)| status.to_string());
+        if status.is_none() && key != '0' {
+            return;
+        }
+
+        self.filters.write().unwrap().status = status;
+        self.apply_filters().await;
+    }
+
+    /// Apply the query if it has no syntax errors, otherwise stay in query mode to fix it.
+    pub async fn submit_query(&mut self) {
+        if self.query.write().unwrap().validate() {
+            self.apply_filters().await;
+        }
+    }
+
+    /// Leave query mode and reload once with the query and all filters composed together.
+    pub async fn apply_filters(&mut self) {
+        log::debug!(widget = "WorkflowTableWidget"; "Applying filters: {}", self.query());
+        self.set_mode(Mode::Normal);
+        self.reload().await;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.query.write().unwrap().set_theme(theme);
+    }
+
+    pub fn set_auto_refresh(&mut self, auto_refresh: bool) {
+        self.auto_refresh = auto_refresh;
+    }
+
+    /// Stop loading in the background, once this widget is no longer displayed.
+    pub fn shutdown(&self) {
+        self.tasks.shutdown();
+    }
+
+    /// Whether keys are being typed into the query input.
+    pub fn is_querying(&self) -> bool {
+        matches!(self.mode, Mode::Query)
+    }
+
     pub fn is_loading(&self) -> bool {
         let state = self.state.read().unwrap();
-        match state.loading_state {
-            LoadingState::Loading => true,
-            _ => false,
-        }
+        state.loading_state.is_busy()
+    }
+
+    pub fn busy_message(&self) -> Option<String> {
+        let state = self.state.read().unwrap();
+        state.loading_state.busy_message()
     }
 
     pub fn is_error(&self) -> (bool, Option<String>) {
@@ -360,25 +1154,24 @@ impl WorkflowTableWidget {
     }
 
     pub async fn next_row(&mut self) {
-        let on_last_row = self.is_on_last_row();
-        if on_last_row {
-            self.load_next_page().await;
-            task::yield_now().await;
-        }
-
-        loop {
-            let on_last_row = self.is_on_last_row();
-            if !on_last_row {
-                break;
-            }
-            task::yield_now().await;
+        if !self.jump_after_next_page(PendingJump::NextRow).await {
+            self.select_next_row();
         }
+    }
 
+    fn select_next_row(&mut self) {
         let mut state = self.state.write().unwrap();
+        if state.len() == 0 {
+            return;
+        }
         let i = match state.table_state.selected() {
             Some(i) => {
-                if i >= state.workflow_executions.len() - 1 {
-                    0
+                if i + 1 >= state.len() {
+                    if self.settings.wrap_navigation {
+                        0
+                    } else {
+                        i
+                    }
                 } else {
                     i + 1
                 }
@@ -389,26 +1182,28 @@ impl WorkflowTableWidget {
         state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
     }
 
+    /// Whether the last row is selected, never when there are no rows to select.
     pub fn is_on_last_row(&self) -> bool {
         let state = self.state.read().unwrap();
         match state.table_state.selected() {
-            Some(i) => {
-                if i >= state.workflow_executions.len() - 1 {
-                    true
-                } else {
-                    false
-                }
-            }
+            Some(i) => state.len() > 0 && i + 1 >= state.len(),
             None => false,
         }
     }
 
     pub fn previous_row(&mut self) {
         let mut state = self.state.write().unwrap();
+        if state.len() == 0 {
+            return;
+        }
         let i = match state.table_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    state.workflow_executions.len() - 1
+                    if self.settings.wrap_navigation {
+                        state.len() - 1
+                    } else {
+                        0
+                    }
                 } else {
                     i - 1
                 }
@@ -419,6 +1214,129 @@ impl WorkflowTableWidget {
         state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
     }
 
+    /// Select the row a page below the selection, loading the next page of workflows first if
+    /// there are fewer rows left than fit on a page.
+    pub async fn next_page(&mut self) {
+        if !self.jump_after_next_page(PendingJump::NextPage).await {
+            self.select_next_page();
+        }
+    }
+
+    fn select_next_page(&mut self) {
+        let mut state = self.state.write().unwrap();
+        if state.len() == 0 {
+            return;
+        }
+        let i = match state.table_state.selected() {
+            Some(i) => (i + state.page_height.max(1)).min(state.len() - 1),
+            None => 0,
+        };
+        state.table_state.select(Some(i));
+        state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
+    }
+
+    /// Select the row a page above the selection, stopping at the first one.
+    pub fn previous_page(&mut self) {
+        let mut state = self.state.write().unwrap();
+        let i = state
+            .table_state
+            .selected()
+            .unwrap_or(0)
+            .saturating_sub(state.page_height.max(1));
+        state.table_state.select(Some(i));
+        state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
+    }
+
+    /// Load the next page of workflows to move the selection by `jump` once it arrives, if
+    /// moving it needs more rows than are loaded. Returns whether the jump waits for the page.
+    async fn jump_after_next_page(&mut self, jump: PendingJump) -> bool {
+        {
+            let mut state = self.state.write().unwrap();
+            if state.pending_jump.is_some() {
+                // The page is already loading, move the selection by the latest key once it's in
+                state.pending_jump = Some(jump);
+                return true;
+            }
+            let has_next_page = state
+                .next_page_token
+                .as_ref()
+                .is_some_and(|token| !token.is_empty());
+            let selected = state.table_state.selected();
+            let needs_next_page = match jump {
+                PendingJump::NextRow => selected.is_some_and(|i| i + 1 >= state.len()),
+                PendingJump::NextPage => {
+                    selected.unwrap_or(0) + state.page_height.max(1) >= state.len()
+                }
+                // The table's last row is the last one loaded
+                PendingJump::LastRow => false,
+            };
+            if !has_next_page || !needs_next_page {
+                return false;
+            }
+            state.pending_jump = Some(jump);
+        }
+
+        self.set_loading_state(LoadingState::Loading);
+        self.load_next_page().await;
+        true
+    }
+
+    /// Move the selection as requested while the last page of workflows was loading, and load
+    /// what's displayed about the workflow it lands on.
+    async fn apply_pending_jump(&mut self) {
+        let pending_jump = self.state.write().unwrap().pending_jump.take();
+        match pending_jump {
+            Some(PendingJump::NextRow) => self.select_next_row(),
+            Some(PendingJump::NextPage) => self.select_next_page(),
+            Some(PendingJump::LastRow) => self.last_row(),
+            None => return,
+        }
+
+        if let Some(execution) = self.selected_without_close_reason() {
+            self.load_close_reason(execution.workflow_id, execution.run_id)
+                .await;
+        }
+        if let Some(execution) = self.selected_without_preview() {
+            self.load_preview(execution.workflow_id, execution.run_id)
+                .await;
+        }
+    }
+
+    /// Grow the page size by a step, or shrink it if not `increase`, and reload with it.
+    pub async fn change_page_size(&mut self, increase: bool) {
+        {
+            let mut page_size = self.page_size.write().unwrap();
+            *page_size = if increase {
+                page_size.saturating_add(PAGE_SIZE_STEP)
+            } else {
+                page_size.saturating_sub(PAGE_SIZE_STEP)
+            }
+            .clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
+            log::debug!(widget = "WorkflowTableWidget"; "Changed page size to {}", *page_size);
+        }
+        self.reload().await;
+    }
+
+    /// Select the first row.
+    pub fn first_row(&mut self) {
+        let mut state = self.state.write().unwrap();
+        if state.len() == 0 {
+            return;
+        }
+        state.table_state.select(Some(0));
+        state.scrollbar_state = state.scrollbar_state.position(0);
+    }
+
+    /// Select the last loaded row.
+    pub fn last_row(&mut self) {
+        let mut state = self.state.write().unwrap();
+        let Some(i) = state.len().checked_sub(1) else {
+            return;
+        };
+        state.table_state.select(Some(i));
+        state.scrollbar_state = state.scrollbar_state.position(i * ITEM_HEIGHT);
+    }
+
     pub fn get_duration_since_last_reload(&self) -> Option<time::Duration> {
         match self.last_reload.try_read() {
             Ok(last_reload) => match *last_reload {
@@ -432,11 +1350,37 @@ impl WorkflowTableWidget {
     pub fn get_selected_workflow_id(&self) -> Option<String> {
         let state = self.state.read().unwrap();
         match state.table_state.selected() {
-            Some(i) => Some(state.workflow_executions[i].workflow_id.clone()),
+            Some(i) => state.get(i).map(|e| e.workflow_id.clone()),
             None => None,
         }
     }
 
+    pub fn get_selected_workflow_execution(&self) -> Option<WorkflowExecution> {
+        let state = self.state.read().unwrap();
+        match state.table_state.selected() {
+            Some(i) => state.get(i).cloned(),
+            None => None,
+        }
+    }
+
+    /// Copy the ID and run ID of the selected workflow to the clipboard, like to pass them to the
+    /// Temporal CLI.
+    pub fn copy_selected_workflow(&mut self) {
+        let Some(execution) = self.get_selected_workflow_execution() else {
+            return;
+        };
+
+        match clipboard::copy(&format!("{} {}", execution.workflow_id, execution.run_id)) {
+            Ok(_) => {
+                log::info!(widget = "WorkflowTableWidget"; "Copied workflow to clipboard");
+                self.copied_at = Some(time::Instant::now());
+            }
+            Err(e) => {
+                log::error!(widget = "WorkflowTableWidget"; "Failed to copy workflow: {}", e)
+            }
+        }
+    }
+
     pub async fn handle_query_key(&mut self, key: event::KeyEvent) {
         match key {
             // Mode switch
@@ -446,11 +1390,12 @@ impl WorkflowTableWidget {
                 ..
             } => self.set_mode(Mode::Normal),
             // Reload workflow table
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
+            // Apply query and filters
             event::KeyEvent {
-                code: event::KeyCode::Char('r'),
-                modifiers: event::KeyModifiers::CONTROL,
+                code: event::KeyCode::Enter,
                 ..
-            } => self.reload().await,
+            } => self.submit_query().await,
             // Pass along to `QueryInput`
             event::KeyEvent {
                 code: event::KeyCode::Char(_),
@@ -475,8 +1420,14 @@ impl WorkflowTableWidget {
         }
     }
 
-    pub async fn handle_normal_key(&mut self, key: event::KeyEvent) -> Option<WorkflowWidget> {
+    pub async fn handle_normal_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
+        let pending_key = self.pending_key.take();
         match key {
+            // Dismiss the error banner
+            event::KeyEvent {
+                code: event::KeyCode::Esc,
+                ..
+            } if self.is_error().0 => self.set_loading_state(LoadingState::Idle),
             // Mode switch
             event::KeyEvent {
                 code: event::KeyCode::Char('q'),
@@ -484,59 +1435,247 @@ impl WorkflowTableWidget {
                 ..
             } => self.set_mode(Mode::Query),
             // Navigation
+            key if self.settings.keybinds.matches(Action::NextRow, &key) => {
+                self.next_row().await;
+                self.request_close_reason().await;
+                self.request_preview().await;
+            }
+            key if self.settings.keybinds.matches(Action::PreviousRow, &key) => {
+                self.previous_row();
+                self.request_close_reason().await;
+                self.request_preview().await;
+            }
             event::KeyEvent {
-                code: event::KeyCode::Char('j'),
+                code: event::KeyCode::PageDown,
                 ..
             }
             | event::KeyEvent {
-                code: event::KeyCode::Down,
+                code: event::KeyCode::Char('d'),
+                modifiers: event::KeyModifiers::CONTROL,
                 ..
-            } => self.next_row().await,
+            } => {
+                self.next_page().await;
+                self.request_close_reason().await;
+                self.request_preview().await;
+            }
             event::KeyEvent {
-                code: event::KeyCode::Char('k'),
+                code: event::KeyCode::PageUp,
                 ..
             }
             | event::KeyEvent {
-                code: event::KeyCode::Up,
+                code: event::KeyCode::Char('u'),
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } => {
+                self.previous_page();
+                self.request_close_reason().await;
+                self.request_preview().await;
+            }
+            // Jump to the first row with gg
+            event::KeyEvent {
+                code: event::KeyCode::Char('g'),
+                modifiers: event::KeyModifiers::NONE,
                 ..
-            } => self.previous_row(),
+            } => match pending_key {
+                Some('g') => {
+                    self.first_row();
+                    self.request_close_reason().await;
+                    self.request_preview().await;
+                }
+                _ => self.pending_key = Some('g'),
+            },
+            event::KeyEvent {
+                code: event::KeyCode::Char('G'),
+                ..
+            } => {
+                self.last_row();
+                self.request_close_reason().await;
+                self.request_preview().await;
+            }
             // Reload workflow table
+            key if self.settings.keybinds.matches(Action::Reload, &key) => self.reload().await,
+            // Filter by the selected workflow's task queue
             event::KeyEvent {
-                code: event::KeyCode::Char('r'),
-                modifiers: event::KeyModifiers::CONTROL,
+                code: event::KeyCode::Char('t'),
+                ..
+            } => self.toggle_task_queue_filter().await,
+            // Filter by children of the selected workflow
+            event::KeyEvent {
+                code: event::KeyCode::Char('c'),
+                modifiers: event::KeyModifiers::NONE,
+                ..
+            } => self.toggle_parent_filter().await,
+            // Filter by the selected workflow's type
+            event::KeyEvent {
+                code: event::KeyCode::Char('w'),
+                ..
+            } => self.toggle_workflow_type_filter().await,
+            // Filter by the start of the selected workflow's ID
+            event::KeyEvent {
+                code: event::KeyCode::Char('i'),
+                ..
+            } => self.toggle_workflow_id_prefix_filter().await,
+            // Filter by how long ago workflows started
+            event::KeyEvent {
+                code: event::KeyCode::Char('s'),
+                ..
+            } => self.cycle_started_within_filter().await,
+            // Copy the selected workflow's IDs
+            event::KeyEvent {
+                code: event::KeyCode::Char('y'),
+                ..
+            } => self.copy_selected_workflow(),
+            // Switch between absolute and relative times
+            event::KeyEvent {
+                code: event::KeyCode::Char('T'),
+                ..
+            } => self.time_format = self.time_format.toggle(),
+            // List more or fewer workflows in each page
+            event::KeyEvent {
+                code: event::KeyCode::Char('+'),
+                ..
+            } => self.change_page_size(true).await,
+            event::KeyEvent {
+                code: event::KeyCode::Char('-'),
+                ..
+            } => self.change_page_size(false).await,
+            // Pin selected workflow to the top of the table
+            event::KeyEvent {
+                code: event::KeyCode::Char('p'),
+                ..
+            } => self.toggle_pin_selected(),
+            // Filter by status
+            event::KeyEvent {
+                code: event::KeyCode::Char(c @ '0'..='9'),
                 ..
-            } => self.reload().await,
+            } => self.set_status_filter(c).await,
             // Select workflow and switch to workflow widget
+            key if self.settings.table_enter_action == EnterAction::Navigate
+                && self.settings.keybinds.matches(Action::ViewWorkflow, &key) =>
+            {
+                return self.view_selected_workflow().map(Navigation::Push);
+            }
+            // Toggle the preview of the selected workflow
             event::KeyEvent {
                 code: event::KeyCode::Enter,
                 ..
+            } if self.settings.table_enter_action == EnterAction::Preview => {
+                self.show_preview = !self.show_preview;
+                self.request_preview().await;
+            }
+            // Switch to workflow widget when Enter previews instead
+            event::KeyEvent {
+                code: event::KeyCode::Char('v'),
+                ..
+            } if self.settings.table_enter_action == EnterAction::Preview => {
+                return self.view_selected_workflow().map(Navigation::Push);
+            }
+            // View counts of the workflows matching the query by status
+            event::KeyEvent {
+                code: event::KeyCode::Char('D'),
+                ..
             } => {
-                if let Some(workflow_id) = self.get_selected_workflow_id() {
-                    let workflow_widget =
-                        WorkflowWidget::new(&self.temporal_client, &workflow_id, None, self.theme);
-                    return Some(workflow_widget);
-                }
+                return Some(Navigation::Push(ViewWidget::Dashboard(
+                    DashboardWidget::new(
+                        &self.temporal_client,
+                        self.duplicate(),
+                        &self.settings,
+                        &self.query(),
+                        self.theme,
+                    ),
+                )));
+            }
+            // View the configuration of the namespace
+            event::KeyEvent {
+                code: event::KeyCode::Char('N'),
+                ..
+            } => {
+                return Some(Navigation::Push(ViewWidget::Namespace(
+                    NamespaceWidget::new(&self.temporal_client, &self.settings, self.theme),
+                )));
             }
             _ => {}
         };
         None
     }
 
+    /// Render a summary of the selected workflow, once it's been described.
+    fn render_preview(&self, area: layout::Rect, buf: &mut buffer::Buffer) {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title("Preview".fg(self.theme.header_foreground))
+            .border_style(style::Style::new().fg(self.theme.border));
+
+        let selected = self.get_selected_workflow_execution();
+        let state = self.state.read().unwrap();
+        let field = |name: &str, value: String| {
+            text::Line::from(vec![
+                text::Span::from(format!("{}: ", name)).fg(self.theme.header_foreground),
+                text::Span::from(value),
+            ])
+        };
+
+        let lines = match selected.and_then(|execution| state.previews.get(&execution.run_id)) {
+            Some(Ok(preview)) => {
+                let execution = &preview.execution;
+                vec![
+                    field(
+                        "Workflow",
+                        format!("{} ({})", execution.workflow_id, execution.run_id),
+                    ),
+                    field(
+                        "Type",
+                        format!("{} on {}", execution.r#type, execution.task_queue),
+                    ),
+                    field(
+                        "Status",
+                        format!(
+                            "{}, started {}, closed {}, took {}",
+                            execution.status_as_string(),
+                            execution.start_time_as_string(),
+                            execution.close_time_as_string(),
+                            execution.execution_duration_as_string(),
+                        ),
+                    ),
+                    field(
+                        "Pending",
+                        format!(
+                            "{} activities, {} children, {} events in history",
+                            preview.pending_activities,
+                            preview.pending_children,
+                            execution.history_length,
+                        ),
+                    ),
+                    field(
+                        "Parent",
+                        preview
+                            .parent_workflow_id
+                            .clone()
+                            .unwrap_or("-".to_string()),
+                    ),
+                ]
+            }
+            Some(Err(e)) => vec![text::Line::from(e.as_str())],
+            None => vec![text::Line::from("Loading preview...")],
+        };
+
+        let preview = widgets::Paragraph::new(lines)
+            .fg(self.theme.foreground)
+            .bg(self.theme.background)
+            .block(block);
+        widgets::Widget::render(preview, area, buf);
+    }
+
     pub fn set_mode(&mut self, mode: Mode) {
         self.mode = mode;
     }
 }
 
 impl Keybindable for WorkflowTableWidget {
-    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<ViewWidget> {
+    async fn handle_key(&mut self, key: event::KeyEvent) -> Option<Navigation> {
         match self.mode {
             Mode::Query => {
-                if let event::KeyEvent {
-                    code: event::KeyCode::Char('r'),
-                    modifiers: event::KeyModifiers::CONTROL,
-                    ..
-                } = key
-                {
+                if self.settings.keybinds.matches(Action::Reload, &key) {
                     self.reload().await;
                     None
                 } else {
@@ -544,23 +1683,57 @@ impl Keybindable for WorkflowTableWidget {
                     None
                 }
             }
-            Mode::Normal => {
-                if let Some(workflow_widget) = self.handle_normal_key(key).await {
-                    Some(ViewWidget::Workflow(workflow_widget))
-                } else {
-                    None
-                }
-            }
+            Mode::Normal => self.handle_normal_key(key).await,
         }
     }
 
     fn keybinds<'k>(&'k self) -> &'k [(&'k str, &'k [&'k str])] {
         match self.mode {
-            Mode::Query => &[("Toggle query", &["Ctrl+q"]), ("Reload", &["Ctrl+r"])],
+            Mode::Query => &[
+                ("Apply", &["Enter"]),
+                ("Toggle query", &["Ctrl+q"]),
+                ("Reload", &["Ctrl+r"]),
+            ],
+            Mode::Normal if self.settings.table_enter_action == EnterAction::Preview => &[
+                ("Up", &["k", "↑"]),
+                ("Down", &["j", "↓"]),
+                ("Page down/up", &["PgDn", "PgUp"]),
+                ("First/last row", &["gg", "G"]),
+                ("Preview", &["Enter"]),
+                ("View workflow", &["v"]),
+                ("Pin workflow", &["p"]),
+                ("Copy IDs", &["y"]),
+                ("Relative times", &["T"]),
+                ("Page size", &["+", "-"]),
+                ("Filter task queue", &["t"]),
+                ("Filter children", &["c"]),
+                ("Filter type", &["w"]),
+                ("Filter ID prefix", &["i"]),
+                ("Filter start time", &["s"]),
+                ("Filter status", &["1-6", "0"]),
+                ("Counts by status", &["D"]),
+                ("Namespace", &["N"]),
+                ("Toggle query", &["Ctrl+q"]),
+                ("Reload", &["Ctrl+r"]),
+            ],
             Mode::Normal => &[
-                ("Up", &["j", "↑"]),
-                ("Down", &["k", "↓"]),
+                ("Up", &["k", "↑"]),
+                ("Down", &["j", "↓"]),
+                ("Page down/up", &["PgDn", "PgUp"]),
+                ("First/last row", &["gg", "G"]),
                 ("View workflow", &["Enter"]),
+                ("Pin workflow", &["p"]),
+                ("Copy IDs", &["y"]),
+                ("Relative times", &["T"]),
+                ("Page size", &["+", "-"]),
+                ("Filter task queue", &["t"]),
+                ("Filter children", &["c"]),
+                ("Filter type", &["w"]),
+                ("Filter ID prefix", &["i"]),
+                ("Filter start time", &["s"]),
+                ("Filter status", &["1-6", "0"]),
+                ("Counts by status", &["D"]),
+                ("Namespace", &["N"]),
                 ("Toggle query", &["Ctrl+q"]),
                 ("Reload", &["Ctrl+r"]),
             ],
@@ -570,9 +1743,66 @@ impl Keybindable for WorkflowTableWidget {
 
 impl widgets::Widget for &WorkflowTableWidget {
     fn render(self, area: layout::Rect, buf: &mut buffer::Buffer) {
-        let vertical =
-            &layout::Layout::vertical([layout::Constraint::Length(3), layout::Constraint::Fill(1)]);
-        let [header_area, body_area] = vertical.areas(area);
+        let query_error = self.state.read().unwrap().query_error.clone();
+        let vertical = &layout::Layout::vertical([
+            layout::Constraint::Length(3),
+            layout::Constraint::Length(if query_error.is_some() { 1 } else { 0 }),
+            layout::Constraint::Fill(1),
+        ]);
+        let [header_area, query_error_area, body_area] = vertical.areas(area);
+
+        if let Some(query_error) = query_error {
+            widgets::Paragraph::new(format!("Invalid query: {}", query_error))
+                .fg(self.theme.foreground)
+                .bg(self.theme.failure_background)
+                .render(query_error_area, buf);
+        }
+
+        let close_reason = self
+            .get_selected_workflow_execution()
+            .and_then(|execution| match execution.status {
+                enums::WorkflowExecutionStatus::Terminated
+                | enums::WorkflowExecutionStatus::Canceled => Some(
+                    self.state
+                        .read()
+                        .unwrap()
+                        .close_reasons
+                        .get(&execution.run_id)
+                        .cloned()
+                        .unwrap_or("Loading close reason...".to_string()),
+                ),
+                _ => None,
+            });
+        let body_vertical = &layout::Layout::vertical([
+            layout::Constraint::Fill(1),
+            layout::Constraint::Length(if close_reason.is_some() { 1 } else { 0 }),
+            layout::Constraint::Length(if self.show_preview { 7 } else { 0 }),
+        ]);
+        let [body_area, close_reason_area, preview_area] = body_vertical.areas(body_area);
+
+        let body_area = match self.is_error() {
+            (true, Some(error)) => {
+                let [error_area, rest_area] = layout::Layout::vertical([
+                    layout::Constraint::Length(1),
+                    layout::Constraint::Fill(1),
+                ])
+                .areas(body_area);
+                render_error_banner(&error, self.theme, error_area, buf);
+                rest_area
+            }
+            _ => body_area,
+        };
+
+        if self.show_preview {
+            self.render_preview(preview_area, buf);
+        }
+
+        if let Some(close_reason) = close_reason {
+            widgets::Paragraph::new(close_reason)
+                .fg(self.theme.foreground)
+                .bg(self.theme.alt_background)
+                .render(close_reason_area, buf);
+        }
 
         let header_horizontal = &layout::Layout::horizontal([
             layout::Constraint::Fill(1),
@@ -580,14 +1810,39 @@ impl widgets::Widget for &WorkflowTableWidget {
         ]);
         let [header_left_area, header_right_area] = header_horizontal.areas(header_area);
 
+        let auto_refresh_string = if self.auto_refresh { " (auto)" } else { "" };
         let last_reload_string = match self.get_duration_since_last_reload() {
-            Some(duration) => format!("Last reload: {}s ago", duration.as_secs()),
-            None => "Last reload: N/A".to_string(),
+            _ if self
+                .copied_at
+                .is_some_and(|copied_at| copied_at.elapsed() < COPIED_NOTICE_DURATION) =>
+            {
+                "Copied!".to_string()
+            }
+            Some(duration) => format!(
+                "Last reload: {} ago{}",
+                humanize_duration(duration),
+                auto_refresh_string
+            ),
+            None => format!("Last reload: N/A{}", auto_refresh_string),
         };
 
-        let last_reload_title = widgets::Paragraph::new(text::Text::from(last_reload_string))
-            .style(style::Style::new().fg(self.theme.foreground))
-            .right_aligned();
+        let count_string = match self.filters.read().unwrap().status.as_ref() {
+            Some(status) => format!("Matched {}", status),
+            None => "Matched".to_string(),
+        };
+        let count_string = match self.state.read().unwrap().count {
+            Some(count) => format!("{}: {}", count_string, count),
+            None => format!("{}: N/A", count_string),
+        };
+        let page_size_string = format!("Page size: {}", *self.page_size.read().unwrap());
+
+        let last_reload_title = widgets::Paragraph::new(text::Text::from_iter([
+            last_reload_string,
+            count_string,
+            page_size_string,
+        ]))
+        .style(style::Style::new().fg(self.theme.foreground))
+        .right_aligned();
 
         let query_input = self.query.read().unwrap();
         widgets::Widget::render(&(*query_input), header_left_area, buf);
@@ -595,10 +1850,13 @@ impl widgets::Widget for &WorkflowTableWidget {
 
         let table_block = widgets::Block::bordered()
             .title(
-                text::Line::from("Workflows")
-                    .left_aligned()
-                    .fg(self.theme.header_foreground)
-                    .bold(),
+                text::Line::from(match self.filters.read().unwrap().clauses() {
+                    clauses if clauses.is_empty() => "Workflows".to_string(),
+                    clauses => format!("Workflows ({})", clauses.join(" AND ")),
+                })
+                .left_aligned()
+                .fg(self.theme.header_foreground)
+                .bold(),
             )
             .border_type(widgets::BorderType::Rounded)
             .border_style(style::Style::new().fg(self.theme.border))
@@ -615,65 +1873,74 @@ impl widgets::Widget for &WorkflowTableWidget {
             .add_modifier(style::Modifier::REVERSED)
             .fg(self.theme.selection_background);
 
-        let header = [
-            "Status",
-            "Type",
-            "Workflow ID",
-            "Task Queue",
-            "Start Time",
-            "Close Time",
-        ]
-        .into_iter()
-        .map(widgets::Cell::from)
-        .collect::<widgets::Row>()
-        .style(header_style)
-        .height(1);
+        let header = COLUMNS
+            .iter()
+            .map(|(name, column_type, _)| {
+                widgets::Cell::from(text::Text::from(*name).alignment(column_type.alignment()))
+            })
+            .collect::<widgets::Row>()
+            .style(header_style)
+            .height(1);
 
         let mut state = self.state.write().unwrap();
+        // Rows within the table's borders and below its header
+        state.page_height = body_area.height.saturating_sub(3) as usize;
 
         let rows = state
-            .workflow_executions
-            .iter()
+            .rows()
+            .into_iter()
             .enumerate()
-            .map(|(i, execution)| {
-                let color = match i % 2 {
-                    0 => self.theme.background,
+            .map(|(i, (pinned, execution))| {
+                let color = match (pinned, i % 2) {
+                    (true, _) => self.theme.header_background,
+                    (_, 0) => self.theme.background,
                     _ => self.theme.alt_background,
                 };
                 let status_color = execution.status_color_from_theme(self.theme);
 
-                widgets::Row::new(vec![
-                    widgets::Cell::from(execution.status_as_string()).bg(status_color),
-                    widgets::Cell::new(execution.r#type.clone()),
-                    widgets::Cell::new(execution.workflow_id.clone()),
-                    widgets::Cell::new(execution.task_queue.clone()),
-                    widgets::Cell::new(
-                        execution
-                            .start_time
-                            .and_then(|dt| Some(format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z"))))
-                            .unwrap_or("".to_string()),
-                    ),
-                    widgets::Cell::new(
-                        execution
-                            .close_time
-                            .and_then(|dt| Some(format!("{}", dt.format("%y-%m-%d %H:%M:%S %Z"))))
-                            .unwrap_or("".to_string()),
-                    ),
-                ])
+                let values = [
+                    execution.status_as_string(),
+                    execution.r#type.clone(),
+                    if self.settings.middle_truncate_ids {
+                        // Fit the width of the Workflow ID column
+                        truncate_middle(&execution.workflow_id, COLUMNS[2].2 as usize)
+                    } else {
+                        execution.workflow_id.clone()
+                    },
+                    execution.task_queue.clone(),
+                    execution
+                        .start_time
+                        .map(|dt| self.time_format.format(dt))
+                        .unwrap_or_default(),
+                    execution
+                        .close_time
+                        .map(|dt| self.time_format.format(dt))
+                        .unwrap_or_default(),
+                ];
+
+                widgets::Row::new(values.into_iter().zip(COLUMNS).enumerate().map(
+                    |(column, (value, (_, column_type, _)))| {
+                        let cell = widgets::Cell::from(
+                            text::Text::from(value).alignment(column_type.alignment()),
+                        );
+                        // Status is always the first column
+                        if column == 0 {
+                            cell.bg(status_color)
+                        } else {
+                            cell
+                        }
+                    },
+                ))
                 .style(style::Style::new().fg(self.theme.foreground).bg(color))
                 .height(1)
-            });
+            })
+            .collect::<Vec<widgets::Row>>();
         let bar = " █ ";
         let table = widgets::Table::new(
             rows,
-            [
-                layout::Constraint::Length(18),
-                layout::Constraint::Length(32),
-                layout::Constraint::Length(64),
-                layout::Constraint::Length(32),
-                layout::Constraint::Length(32),
-                layout::Constraint::Length(32),
-            ],
+            COLUMNS
+                .iter()
+                .map(|(_, _, width)| layout::Constraint::Length(*width)),
         )
         .block(table_block)
         .header(header)
@@ -690,5 +1957,172 @@ impl widgets::Widget for &WorkflowTableWidget {
         .highlight_spacing(widgets::HighlightSpacing::Always);
 
         widgets::StatefulWidget::render(table, body_area, buf, &mut state.table_state);
+
+        let content_length = state.len() * ITEM_HEIGHT;
+        state.scrollbar_state = state.scrollbar_state.content_length(content_length);
+        let scrollbar = widgets::Scrollbar::new(widgets::ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(style::Style::new().fg(self.theme.border));
+        // Within the table's rounded corners
+        widgets::StatefulWidget::render(
+            scrollbar,
+            body_area.inner(layout::Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            buf,
+            &mut state.scrollbar_state,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn press(input: &mut QueryInput, code: event::KeyCode) {
+        input.handle_key(event::KeyEvent::from(code)).await;
+    }
+
+    async fn type_text(input: &mut QueryInput, text: &str) {
+        for c in text.chars() {
+            press(input, event::KeyCode::Char(c)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn query_input_inserts_between_multibyte_characters() {
+        let mut input = QueryInput::default();
+        type_text(&mut input, "añb").await;
+        press(&mut input, event::KeyCode::Left).await;
+        press(&mut input, event::KeyCode::Left).await;
+        type_text(&mut input, "é").await;
+
+        assert_eq!(input.query(), "aéñb");
+        assert_eq!(input.cursor, "aé".len());
+    }
+
+    #[tokio::test]
+    async fn query_input_removes_multibyte_characters_before_the_cursor() {
+        let mut input = QueryInput::default();
+        type_text(&mut input, "añb").await;
+        press(&mut input, event::KeyCode::Left).await;
+        press(&mut input, event::KeyCode::Backspace).await;
+
+        assert_eq!(input.query(), "ab");
+        assert_eq!(input.cursor, "a".len());
+
+        press(&mut input, event::KeyCode::Backspace).await;
+        press(&mut input, event::KeyCode::Backspace).await;
+
+        assert_eq!(input.query(), "b");
+        assert_eq!(input.cursor, 0);
+    }
+
+    #[tokio::test]
+    async fn query_input_moves_over_multibyte_characters() {
+        let mut input = QueryInput::default();
+        type_text(&mut input, "ñé").await;
+        assert_eq!(input.cursor, "ñé".len());
+
+        press(&mut input, event::KeyCode::Left).await;
+        assert_eq!(input.cursor, "ñ".len());
+        press(&mut input, event::KeyCode::Left).await;
+        press(&mut input, event::KeyCode::Left).await;
+        assert_eq!(input.cursor, 0);
+
+        press(&mut input, event::KeyCode::Right).await;
+        assert_eq!(input.cursor, "ñ".len());
+        press(&mut input, event::KeyCode::Right).await;
+        press(&mut input, event::KeyCode::Right).await;
+        // Resting on the trailing space
+        assert_eq!(input.cursor, "ñé".len());
+    }
+
+    #[test]
+    fn quick_filters_compose_every_clause() {
+        let filters = QuickFilters {
+            status: Some("Running".to_string()),
+            workflow_type: Some("Order".to_string()),
+            workflow_id_prefix: Some("order-".to_string()),
+            task_queue: Some("orders".to_string()),
+            parent_workflow_id: Some("parent \"1\"".to_string()),
+            started_within: Some(chrono::TimeDelta::hours(24)),
+        };
+        let now = chrono::DateTime::from_timestamp(2 * 24 * 60 * 60, 0).unwrap();
+
+        assert_eq!(
+            filters.clauses_at(now),
+            vec![
+                "ExecutionStatus = \"Running\"",
+                "WorkflowType = \"Order\"",
+                "WorkflowId STARTS_WITH \"order-\"",
+                "TaskQueue = \"orders\"",
+                "ParentWorkflowId = \"parent \\\"1\\\"\"",
+                "StartTime >= \"1970-01-02T00:00:00Z\"",
+            ]
+        );
+        assert!(QuickFilters::default().clauses().is_empty());
+    }
+
+    #[test]
+    fn quick_filters_cycle_through_start_time_windows() {
+        let mut filters = QuickFilters::default();
+        for hours in STARTED_WITHIN_HOURS {
+            filters.cycle_started_within();
+            assert_eq!(
+                filters.started_within,
+                Some(chrono::TimeDelta::hours(hours))
+            );
+        }
+        filters.cycle_started_within();
+        assert_eq!(filters.started_within, None);
+    }
+
+    #[test]
+    fn workflow_id_prefix_ends_at_the_last_separator() {
+        assert_eq!(workflow_id_prefix("order-1234"), "order-");
+        assert_eq!(workflow_id_prefix("billing:invoice_42"), "billing:invoice_");
+        assert_eq!(workflow_id_prefix("cron"), "cron");
+    }
+
+    #[test]
+    fn query_input_clamps_errors_to_a_character_boundary() {
+        let mut input = QueryInput::default();
+        input.set_query("año");
+
+        // Within the two bytes of ñ
+        input.set_error("error", 2);
+        assert_eq!(input.error, Some(("error".to_string(), "a".len())));
+
+        // Past the end, on the trailing space
+        input.set_error("error", 100);
+        assert_eq!(input.error, Some(("error".to_string(), "año".len())));
+
+        input.set_query("");
+        input.set_error("error", 100);
+        assert_eq!(input.error, Some(("error".to_string(), 0)));
+    }
+
+    #[test]
+    fn close_reason_reads_terminated_and_canceled_events() {
+        let terminated =
+            history::history_event::Attributes::WorkflowExecutionTerminatedEventAttributes(
+                history::WorkflowExecutionTerminatedEventAttributes {
+                    reason: "stuck".to_string(),
+                    identity: "ops@example.com".to_string(),
+                    ..Default::default()
+                },
+            );
+        assert_eq!(
+            close_reason(terminated).as_deref(),
+            Some("Terminated by ops@example.com: stuck")
+        );
+
+        let canceled = history::history_event::Attributes::WorkflowExecutionCanceledEventAttributes(
+            history::WorkflowExecutionCanceledEventAttributes::default(),
+        );
+        assert_eq!(close_reason(canceled), None);
     }
 }